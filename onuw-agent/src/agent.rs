@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::{
     interface::{
@@ -10,6 +10,7 @@ use crate::{
 };
 use fallible_iterator::{FallibleIterator, IteratorExt};
 use onuw_game::game::GamePlayer;
+use rand::{seq::SliceRandom, thread_rng};
 use tokio::sync::{
     mpsc::{self, UnboundedSender},
     oneshot,
@@ -25,7 +26,7 @@ pub struct Agent {
 }
 
 impl Agent {
-    fn new(players: Vec<GamePlayer>, roles: HashMap<String, usize>) -> AgentResult<Self> {
+    fn new(players: Vec<GamePlayer>, roles: BTreeMap<String, usize>) -> AgentResult<Self> {
         let possible_roles = roles
             .into_iter()
             .map(|(k, v)| Ok((KnownRole::try_from(k.as_str())?, v)))
@@ -47,7 +48,7 @@ impl Agent {
             loop {
                 let req = rx.recv().await;
 
-                if let Some((sender, Request::Initialize { players, roles })) = req {
+                if let Some((sender, Request::Initialize { players, roles, .. })) = req {
                     match Self::new(players, roles) {
                         Ok(r) => {
                             self_ = r;
@@ -66,6 +67,16 @@ impl Agent {
             while let Some((sender, req)) = rx.recv().await {
                 match req {
                     Request::ChoosePlayer(_) => todo!(),
+                    // the agent can't judge who to point at yet, so it points at random
+                    Request::PointAt(_, players) => {
+                        let response = players
+                            .choose(&mut thread_rng())
+                            .map(|player| Some(Response::Player(player.clone())))
+                            .ok_or(AgentError::CommunicationError {
+                                error: "there is nobody to point at".to_string(),
+                            });
+                        sender.send(response).unwrap();
+                    }
                     Request::ChooseBool() => todo!(),
                     Request::ChooseNum(_) => todo!(),
                     Request::ShowMessage(_) => todo!(),
@@ -76,6 +87,16 @@ impl Agent {
                     }
                     Request::ShowRole(_, _) => todo!(),
                     Request::ShowRoleType(_, _) => todo!(),
+                    // notifications the agent does not reason about yet
+                    Request::ShowReveal(_)
+                    | Request::ShowFaceUp(_, _)
+                    | Request::ShowArtifact(_, _)
+                    | Request::ShowMark(_, _)
+                    | Request::ShowInstruction(_)
+                    | Request::ShowActed(_)
+                    | Request::ShowPointed(_, _) => {
+                        sender.send(Ok(None)).unwrap();
+                    }
                 }
             }
         });
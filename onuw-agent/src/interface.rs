@@ -11,13 +11,13 @@ use self::{
 use crate::agent::{Agent, AgentChannelItem};
 use async_trait::async_trait;
 use onuw_game::{
-    game::time::ONUWTime,
+    game::{artifact::Artifact, mark::Mark, snapshot::Reveal, time::ONUWTime, GamePlayer},
     playerinterface::{
         error::PlayerInterfaceError, message::Message, roletarget::RoleTarget, PlayerInterface,
     },
     role::{roletype::RoleType, Role},
 };
-use std::{collections::HashMap, sync::Arc};
+use std::collections::BTreeMap;
 use tokio::sync::{
     mpsc::UnboundedSender,
     oneshot::{self},
@@ -59,6 +59,72 @@ impl PlayerInterface for AgentInterface {
         self.name.as_str()
     }
 
+    #[instrument(level = "trace")]
+    async fn show_face_up(
+        &self,
+        target: RoleTarget,
+        role: &dyn Role,
+    ) -> Result<(), PlayerInterfaceError> {
+        match self
+            .send(Request::ShowFaceUp(target, role.effective_id()))
+            .await?
+        {
+            None => Ok(()),
+            Some(r) => Err(PlayerInterfaceError::UnexpectedResponse(format!("{:?}", r))),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn show_artifact(
+        &self,
+        target: RoleTarget,
+        artifact: &Artifact,
+    ) -> Result<(), PlayerInterfaceError> {
+        match self.send(Request::ShowArtifact(target, *artifact)).await? {
+            None => Ok(()),
+            Some(r) => Err(PlayerInterfaceError::UnexpectedResponse(format!("{:?}", r))),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn show_mark(&self, target: RoleTarget, mark: &Mark) -> Result<(), PlayerInterfaceError> {
+        match self.send(Request::ShowMark(target, *mark)).await? {
+            None => Ok(()),
+            Some(r) => Err(PlayerInterfaceError::UnexpectedResponse(format!("{:?}", r))),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn show_instruction(&self, instruction: &str) -> Result<(), PlayerInterfaceError> {
+        match self
+            .send(Request::ShowInstruction(instruction.to_string()))
+            .await?
+        {
+            None => Ok(()),
+            Some(r) => Err(PlayerInterfaceError::UnexpectedResponse(format!("{:?}", r))),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn show_pointed(
+        &self,
+        pointer: RoleTarget,
+        target: RoleTarget,
+    ) -> Result<(), PlayerInterfaceError> {
+        match self.send(Request::ShowPointed(pointer, target)).await? {
+            None => Ok(()),
+            Some(r) => Err(PlayerInterfaceError::UnexpectedResponse(format!("{:?}", r))),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn show_acted(&self, target: RoleTarget) -> Result<(), PlayerInterfaceError> {
+        match self.send(Request::ShowActed(target)).await? {
+            None => Ok(()),
+            Some(r) => Err(PlayerInterfaceError::UnexpectedResponse(format!("{:?}", r))),
+        }
+    }
+
     #[instrument(level = "trace")]
     async fn show_role(
         &self,
@@ -92,8 +158,8 @@ impl PlayerInterface for AgentInterface {
     #[instrument(level = "trace")]
     async fn choose_player<'a>(
         &self,
-        players: &'a [&'a Arc<dyn PlayerInterface>],
-    ) -> Result<Arc<dyn PlayerInterface>, PlayerInterfaceError> {
+        players: &'a [&'a GamePlayer],
+    ) -> Result<GamePlayer, PlayerInterfaceError> {
         match self
             .send(Request::ChoosePlayer(
                 players.iter().map(|v| v.to_owned().to_owned()).collect(),
@@ -108,6 +174,27 @@ impl PlayerInterface for AgentInterface {
         }
     }
 
+    #[instrument(level = "trace")]
+    async fn point_at<'a>(
+        &self,
+        question: &str,
+        players: &'a [&'a GamePlayer],
+    ) -> Result<GamePlayer, PlayerInterfaceError> {
+        match self
+            .send(Request::PointAt(
+                question.to_string(),
+                players.iter().map(|v| v.to_owned().to_owned()).collect(),
+            ))
+            .await?
+        {
+            Some(Response::Player(p)) => Ok(p),
+            None => Err(PlayerInterfaceError::UnexpectedResponse(
+                "empty".to_string(),
+            )),
+            Some(r) => Err(PlayerInterfaceError::UnexpectedResponse(format!("{:?}", r))),
+        }
+    }
+
     #[instrument(level = "trace")]
     async fn choose_bool(&self) -> Result<bool, PlayerInterfaceError> {
         match self.send(Request::ChooseBool()).await? {
@@ -141,12 +228,14 @@ impl PlayerInterface for AgentInterface {
     #[instrument(level = "trace")]
     async fn handshake<'a>(
         &self,
-        players: &'a [&'a Arc<dyn PlayerInterface>],
-        roles: &'a HashMap<String, usize>,
+        players: &'a [&'a GamePlayer],
+        seats: &'a [&'a GamePlayer],
+        roles: &'a BTreeMap<String, usize>,
     ) -> Result<(), PlayerInterfaceError> {
         match self
             .send(Request::Initialize {
                 players: players.iter().map(|v| v.to_owned().to_owned()).collect(),
+                seats: seats.iter().map(|v| v.to_owned().to_owned()).collect(),
                 roles: roles.to_owned(),
             })
             .await?
@@ -164,6 +253,14 @@ impl PlayerInterface for AgentInterface {
         }
     }
 
+    #[instrument(level = "trace")]
+    async fn show_reveal(&self, reveal: &Reveal) -> Result<(), PlayerInterfaceError> {
+        match self.send(Request::ShowReveal(reveal.clone())).await? {
+            None => Ok(()),
+            Some(r) => Err(PlayerInterfaceError::UnexpectedResponse(format!("{:?}", r))),
+        }
+    }
+
     #[instrument(level = "trace")]
     async fn show_win(&self, won_game: bool) -> Result<(), PlayerInterfaceError> {
         match self.send(Request::ShowWin(won_game)).await? {
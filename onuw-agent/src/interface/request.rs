@@ -1,23 +1,32 @@
 use onuw_game::{
-    game::{time::ONUWTime, GamePlayer},
+    game::{artifact::Artifact, mark::Mark, snapshot::Reveal, time::ONUWTime, GamePlayer},
     playerinterface::{message::Message, roletarget::RoleTarget},
 };
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 #[derive(Debug)]
 pub enum Request {
     Initialize {
         players: Vec<GamePlayer>,
-        roles: HashMap<String, usize>,
+        seats: Vec<GamePlayer>,
+        roles: BTreeMap<String, usize>,
     },
     ChoosePlayer(Vec<GamePlayer>),
+    PointAt(String, Vec<GamePlayer>),
     ChooseBool(),
     ChooseNum(Vec<isize>),
     ShowMessage(Message),
     ShowRole(RoleTarget, String),
     ShowRoleType(RoleTarget, String),
+    ShowFaceUp(RoleTarget, String),
+    ShowArtifact(RoleTarget, Artifact),
+    ShowMark(RoleTarget, Mark),
+    ShowInstruction(String),
+    ShowActed(RoleTarget),
+    ShowPointed(RoleTarget, RoleTarget),
     ShowWin(bool),
     ShowTime(Time),
+    ShowReveal(Reveal),
 }
 
 #[derive(Debug)]
@@ -1,26 +1,79 @@
 use std::fmt::Display;
 
 use crate::interface::error::AgentError;
+use onuw_game::role::roles::ROLES_STRINGS;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub(crate) enum Role {
+    Sentinel,
+    Copycat,
     Doppelganger,
+    Vampire,
+    Count,
+    Master,
+    Renfield,
+    Diseased,
+    Cupid,
+    Instigator,
+    Priest,
+    Assassin,
+    ApprenticeAssassin,
+    Marksman,
+    Pickpocket,
+    Gremlin,
+    Oracle,
     Werewolves,
+    AlphaWolf,
+    MysticWolf,
+    DreamWolf,
+    Alien,
+    SyntheticAlien,
+    Groob,
+    Zerb,
+    Cow,
+    Leader,
+    Psychic,
+    Rascal,
+    Blob,
+    Mortician,
+    Exposer,
+    BodySnatcher,
     Minion,
+    ApprenticeTanner,
+    Squire,
     Masons,
+    Thing,
     Seer,
+    ApprenticeSeer,
+    ParanormalInvestigator,
+    Beholder,
     Robber,
+    Witch,
     Troublemaker,
+    VillageIdiot,
+    AuraSeer,
     Drunk,
     Insomniac,
+    Revealer,
+    Curator,
+    Nostradamus,
+    Empath,
     Villager,
+    Prince,
+    Cursed,
     Hunter,
+    Bodyguard,
     Tanner,
+    /// A role described by a script rather than built in.
+    Scripted(String),
 }
 
 impl Display for Role {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Role::Scripted(name) => write!(f, "{}", name),
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
 
@@ -29,18 +82,66 @@ impl<'a> TryFrom<&'a str> for Role {
 
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
         match value {
+            "Sentinel" => Ok(Self::Sentinel),
             "Doppelganger" => Ok(Self::Doppelganger),
+            "Vampire" => Ok(Self::Vampire),
+            "Count" => Ok(Self::Count),
+            "Master" => Ok(Self::Master),
+            "Renfield" => Ok(Self::Renfield),
+            "Diseased" => Ok(Self::Diseased),
+            "Cupid" => Ok(Self::Cupid),
+            "Instigator" => Ok(Self::Instigator),
+            "Priest" => Ok(Self::Priest),
+            "Assassin" => Ok(Self::Assassin),
+            "ApprenticeAssassin" => Ok(Self::ApprenticeAssassin),
+            "Marksman" => Ok(Self::Marksman),
+            "Copycat" => Ok(Self::Copycat),
+            "Pickpocket" => Ok(Self::Pickpocket),
+            "Gremlin" => Ok(Self::Gremlin),
             "Werewolf" => Ok(Self::Werewolves),
+            "AlphaWolf" => Ok(Self::AlphaWolf),
+            "MysticWolf" => Ok(Self::MysticWolf),
+            "DreamWolf" => Ok(Self::DreamWolf),
+            "Alien" => Ok(Self::Alien),
+            "SyntheticAlien" => Ok(Self::SyntheticAlien),
+            "Groob" => Ok(Self::Groob),
+            "Zerb" => Ok(Self::Zerb),
+            "Oracle" => Ok(Self::Oracle),
+            "Cow" => Ok(Self::Cow),
+            "Leader" => Ok(Self::Leader),
+            "Psychic" => Ok(Self::Psychic),
+            "Rascal" => Ok(Self::Rascal),
+            "Blob" => Ok(Self::Blob),
+            "Mortician" => Ok(Self::Mortician),
+            "Exposer" => Ok(Self::Exposer),
+            "BodySnatcher" => Ok(Self::BodySnatcher),
             "Minion" => Ok(Self::Minion),
+            "ApprenticeTanner" => Ok(Self::ApprenticeTanner),
+            "Squire" => Ok(Self::Squire),
             "Masons" => Ok(Self::Masons),
+            "Thing" => Ok(Self::Thing),
             "Seer" => Ok(Self::Seer),
+            "ApprenticeSeer" => Ok(Self::ApprenticeSeer),
+            "ParanormalInvestigator" => Ok(Self::ParanormalInvestigator),
+            "Beholder" => Ok(Self::Beholder),
             "Robber" => Ok(Self::Robber),
+            "Witch" => Ok(Self::Witch),
             "Troublemaker" => Ok(Self::Troublemaker),
+            "VillageIdiot" => Ok(Self::VillageIdiot),
+            "AuraSeer" => Ok(Self::AuraSeer),
             "Drunk" => Ok(Self::Drunk),
             "Insomniac" => Ok(Self::Insomniac),
+            "Revealer" => Ok(Self::Revealer),
+            "Curator" => Ok(Self::Curator),
+            "Nostradamus" => Ok(Self::Nostradamus),
+            "Empath" => Ok(Self::Empath),
             "Villager" => Ok(Self::Villager),
+            "Prince" => Ok(Self::Prince),
+            "Cursed" => Ok(Self::Cursed),
             "Hunter" => Ok(Self::Hunter),
+            "Bodyguard" => Ok(Self::Bodyguard),
             "Tanner" => Ok(Self::Tanner),
+            role if ROLES_STRINGS.contains_key(role) => Ok(Self::Scripted(role.to_owned())),
             role => Err(AgentError::RoleParseError {
                 role: role.to_owned(),
             }),
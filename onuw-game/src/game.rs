@@ -1,75 +1,163 @@
-mod gamerror;
+pub mod artifact;
+pub mod gamerror;
+pub mod mark;
 mod nightaction;
 
 pub mod options;
+pub mod player;
+pub mod snapshot;
 pub mod time;
 pub(crate) mod voteaction;
 
+use self::artifact::Artifact;
+use self::gamerror::GameError;
+use self::mark::{Mark, VIEW_MARKS_PRIORITY};
 use self::nightaction::NightAction;
-use self::options::Options;
+use self::options::{Options, MAX_CENTER_COUNT};
+use self::snapshot::{
+    CenterSnapshot, GameSnapshot, MessageView, NightEvent, Observation, ObservedTarget,
+    PlayerSnapshot, PlayerView, RandomDraw, Reveal,
+};
 use self::time::ONUWTime;
 use crate::game::voteaction::ONUWGameVoteAction;
 use crate::playerinterface::message::Message;
 use crate::playerinterface::roletarget::RoleTarget;
-use crate::playerinterface::{error, PlayerInterface};
-use crate::role::{roletype::RoleType, ActionPriority, Role};
+use crate::playerinterface::{error::PlayerInterfaceError, PlayerInterface};
+use crate::role::{
+    copy_chain,
+    roles::{alphawolf::AlphaWolf, werewolf::Werewolf},
+    roletype::RoleType,
+    ActionPriority, Role,
+};
 use derive_getters::Getters;
 use futures::future::join_all;
-use futures::stream;
-use futures::Future;
-use futures::StreamExt;
-use gamerror::GameError;
 use itertools::Itertools;
-use rand::{seq::SliceRandom, thread_rng};
-use std::collections::HashSet;
-use std::fmt::Debug;
+use rand::{rngs::StdRng, seq::SliceRandom, thread_rng, Rng, SeedableRng};
+use std::fmt::{Debug, Display};
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     error::Error,
-    sync::Arc,
+    sync::{Arc, Mutex},
+    time::Duration,
 };
 use tokio::sync::RwLock;
+use tokio::time::{sleep_until, timeout_at, Instant};
 use tracing::debug;
 use tracing::{error, instrument, warn};
 
-pub type GamePlayer = Arc<dyn PlayerInterface>;
+pub use self::player::{GamePlayer, PlayerId};
 pub type GameRole = Arc<RwLock<Box<dyn Role>>>;
 
 #[derive(Getters)]
 pub struct ONUWGame {
-    players: HashMap<GamePlayer, GameRole>,
+    /// Everything keyed by player is ordered, so that players are asked, shown and woken in the
+    /// same order every game.
+    #[getter(skip)]
+    players: BTreeMap<GamePlayer, GameRole>,
     // roles: MultiSet<GameRole>,
-    players_by_type: HashMap<RoleType, HashSet<GamePlayer>>,
+    #[getter(skip)]
+    players_by_type: HashMap<RoleType, BTreeSet<GamePlayer>>,
+    #[getter(skip)]
     centerroles: Vec<GameRole>,
+    #[getter(skip)]
+    seats: Vec<GamePlayer>,
+    #[getter(skip)]
+    werewolf_card: Option<GameRole>,
+    #[getter(skip)]
+    face_up: Vec<GamePlayer>,
+    #[getter(skip)]
+    face_up_center: Vec<usize>,
+    #[getter(skip)]
+    artifacts: BTreeMap<GamePlayer, Artifact>,
+    #[getter(skip)]
+    shields: BTreeSet<GamePlayer>,
+    #[getter(skip)]
+    marks: BTreeMap<GamePlayer, Mark>,
+    /// The type each player had before a mark moved them to another team.
+    #[getter(skip)]
+    unmarked_types: BTreeMap<GamePlayer, RoleType>,
+    #[getter(skip)]
+    dealt_roles: BTreeMap<GamePlayer, String>,
+    #[getter(skip)]
+    dealt_centerroles: Vec<String>,
+    #[getter(skip)]
+    observations: Mutex<BTreeMap<GamePlayer, Vec<Observation>>>,
+    /// The player whose night action is currently being performed.
+    #[getter(skip)]
+    acting: Option<GamePlayer>,
+    #[getter(skip)]
+    night_events: Mutex<Vec<NightEvent>>,
+    /// The roles players took on because of the vote, such as a Cursed who became a werewolf.
+    #[getter(skip)]
+    turned: BTreeMap<GamePlayer, GameRole>,
+    #[getter(skip)]
+    messages: Vec<Message>,
     options: Options,
+    #[getter(skip)]
+    seed: u64,
+    #[getter(skip)]
+    rng: StdRng,
+    #[getter(skip)]
+    random_draws: Vec<RandomDraw>,
     nightactions: BTreeMap<ActionPriority, Vec<NightAction>>,
-    votes: Option<HashMap<Arc<dyn PlayerInterface>, Arc<dyn PlayerInterface>>>,
-    dead: Option<HashSet<Arc<dyn PlayerInterface>>>,
-    winners: Option<HashSet<Arc<dyn PlayerInterface>>>,
+    votes: Option<BTreeMap<GamePlayer, GamePlayer>>,
+    dead: Option<BTreeSet<GamePlayer>>,
+    winners: Option<BTreeSet<GamePlayer>>,
 }
 
 impl ONUWGame {
     #[instrument(level = "trace")]
     pub async fn new(
-        players: Vec<GamePlayer>,
+        players: Vec<Arc<dyn PlayerInterface>>,
         roles: Vec<Box<dyn Role>>,
         options: Options,
     ) -> Result<Self, GameError> {
-        if players.len() != roles.len() - 3 {
+        let center = *options.center_count();
+
+        if center > MAX_CENTER_COUNT {
+            Err(GameError::InvalidCenterCount { center })
+        } else if players.len() + center != roles.len() {
             Err(GameError::InvalidRoleCount {
                 roles: roles.len(),
                 players: players.len(),
+                center,
             })
         } else {
+            // the Alpha Wolf needs an extra werewolf card next to the center
+            let werewolf_card = roles
+                .iter()
+                .any(|r| r.id() == AlphaWolf::new().id())
+                .then(|| Arc::new(RwLock::new(Box::new(Werewolf::new()) as Box<dyn Role>)));
+
+            let marks_in_play = roles.iter().any(|r| Mark::placed_by(r.as_ref()));
+
             let mut shuffled_roles: Vec<_> =
                 roles.into_iter().map(RwLock::new).map(Arc::new).collect();
 
+            let players: Vec<_> = players
+                .into_iter()
+                .enumerate()
+                .map(|(i, interface)| GamePlayer::new(PlayerId(i), interface))
+                .collect();
+
+            let seed = options.seed().unwrap_or_else(|| thread_rng().gen());
+            let mut rng = StdRng::seed_from_u64(seed);
+
             if !options.debug_set_roles() {
-                shuffled_roles.as_mut_slice().shuffle(&mut thread_rng());
+                shuffled_roles.as_mut_slice().shuffle(&mut rng);
             }
 
+            // seats are shuffled after the deal, so a seed deals the same cards either way
+            let mut seats = players.clone();
+            if *options.random_seating() {
+                seats.as_mut_slice().shuffle(&mut rng);
+            }
+            let marks = players
+                .iter()
+                .map(|p| (p.clone(), Mark::default()))
+                .collect();
             let mut rolesitr = shuffled_roles.into_iter();
-            let mut assigned_roles = HashMap::new();
+            let mut assigned_roles = BTreeMap::new();
 
             players.into_iter().for_each(|player| {
                 assigned_roles.insert(player, rolesitr.next().unwrap());
@@ -77,23 +165,47 @@ impl ONUWGame {
 
             let remaining_roles: Vec<_> = rolesitr.collect();
 
+            let mut dealt_roles = BTreeMap::new();
+            for (player, role) in assigned_roles.iter() {
+                dealt_roles.insert(player.clone(), role.read().await.id());
+            }
+
+            let mut dealt_centerroles = Vec::new();
+            for role in remaining_roles.iter() {
+                dealt_centerroles.push(role.read().await.id());
+            }
+
+            let mut players_by_type = HashMap::<_, BTreeSet<_>>::new();
+            for (player, role) in assigned_roles.iter() {
+                players_by_type
+                    .entry(role.read().await.role_type())
+                    .or_default()
+                    .insert(player.clone());
+            }
+
             let mut game = Self {
                 // roles: roles.into_iter().collect(),
-                players_by_type: stream::iter(assigned_roles.clone())
-                    .fold(
-                        HashMap::<_, HashSet<_>>::new(),
-                        |mut map: _, (player, role)| async move {
-                            map.entry(role.read().await.role_type())
-                                .and_modify(|v: &mut _| {
-                                    v.insert(player.clone());
-                                })
-                                .or_insert([player.clone()].into_iter().collect());
-                            map
-                        },
-                    )
-                    .await,
+                players_by_type,
                 centerroles: remaining_roles.clone(),
+                seats,
+                werewolf_card,
+                face_up: Vec::new(),
+                face_up_center: Vec::new(),
+                artifacts: BTreeMap::new(),
+                shields: BTreeSet::new(),
+                marks,
+                unmarked_types: BTreeMap::new(),
+                dealt_roles,
+                dealt_centerroles,
+                observations: Mutex::new(BTreeMap::new()),
+                acting: None,
+                night_events: Mutex::new(Vec::new()),
+                turned: BTreeMap::new(),
+                messages: Vec::new(),
                 options,
+                seed,
+                rng,
+                random_draws: Vec::new(),
                 nightactions: BTreeMap::new(),
                 players: assigned_roles.clone(),
                 votes: None,
@@ -101,9 +213,7 @@ impl ONUWGame {
                 winners: None,
             };
 
-            let mut assigned_roles_iter = stream::iter(assigned_roles);
-
-            while let Some((player, role)) = assigned_roles_iter.next().await {
+            for (player, role) in assigned_roles {
                 game.add_night_action(&role, Some(&player)).await;
             }
 
@@ -111,6 +221,13 @@ impl ONUWGame {
                 game.add_night_action(&role, None).await;
             }
 
+            if marks_in_play {
+                game.nightactions
+                    .entry(VIEW_MARKS_PRIORITY.to_string())
+                    .or_default()
+                    .push(NightAction::ViewMarks);
+            }
+
             Ok(game)
         }
     }
@@ -140,82 +257,555 @@ impl ONUWGame {
             .or_insert_with(|| vec![val]);
     }
 
-    #[instrument(level = "trace" skip(onfake))]
-    pub async fn perform_next_night_action<Fut: Future<Output = ()>>(
-        &mut self,
-        onfake: fn() -> Fut,
-    ) -> Result<(), GameError> {
+    #[instrument(level = "trace")]
+    pub async fn perform_next_night_action(&mut self) -> Result<(), GameError> {
         let (priority, actions) = self
             .nightactions
             .pop_first()
             .ok_or(GameError::NoMoreNightActions)?;
 
-        let mut last_role = None;
+        // the role whose turn it is, how long each of its players gets and when the turn is
+        // over, unless it ends right away
+        let mut turn: Option<(String, Duration, Option<Instant>)> = None;
 
         for action in actions {
-            match action {
-                NightAction::Real(role, player) => {
-                    let mut role = role.write().await;
+            let role = match &action {
+                NightAction::Real(role, _) | NightAction::Fake(role) => role.clone(),
+                NightAction::ViewMarks => {
+                    self.view_marks().await;
+                    continue;
+                }
+            };
 
-                    if last_role != Some(role.effective_id()) {
-                        last_role = Some(role.effective_id());
-                        self.announce_time(&ONUWTime::Night(role.as_ref())).await;
+            {
+                let role = role.read().await;
+                let role_id = role.effective_id();
+
+                if turn.as_ref().map(|(id, _, _)| id) != Some(&role_id) {
+                    if let Some((_, _, ends)) = turn.take() {
+                        Self::finish_night_turn(ends).await;
                     }
 
-                    debug!("performing action of {:?} for player {:?}", role, player);
-                    role.action_at_priority(&priority, self, &player).await;
+                    self.announce_time(&ONUWTime::Night(role.as_ref())).await;
+                    let length = self
+                        .options
+                        .night_turn_length_for(&role_id)
+                        .sample(&mut self.rng);
+                    let ends = (!length.is_zero()).then(|| Instant::now() + length);
+                    turn = Some((role_id, length, ends));
                 }
-                NightAction::Fake(role) => {
-                    let role = role.read().await;
+            }
 
-                    if last_role != Some(role.effective_id()) {
-                        last_role = Some(role.effective_id());
-                        self.announce_time(&ONUWTime::Night(role.as_ref())).await;
+            match action {
+                NightAction::Real(_, player) if self.mark_of(&player).blocks_night_action() => {
+                    debug!("{:?} is too afraid to perform their action", player);
+                }
+                NightAction::Real(_, player) => {
+                    let mut role = role.write().await;
+                    debug!("performing action of {:?} for player {:?}", role, player);
+                    // every player holding the role gets the whole turn, so a slow first
+                    // werewolf doesn't use up the time of the others
+                    let length = turn
+                        .as_ref()
+                        .map_or(Duration::ZERO, |(_, length, _)| *length);
+                    let ends = (!length.is_zero()).then(|| Instant::now() + length);
+                    self.acting = Some(player.clone());
+                    let action = role.action_at_priority(&priority, self, &player);
+                    match ends {
+                        Some(ends) => {
+                            if timeout_at(ends, action).await.is_err() {
+                                warn!("{:?} ran out of time for their night action", player);
+                            }
+                        }
+                        None => {
+                            action.await;
+                        }
                     }
-
-                    debug!("skipping action of {:?}", role);
-                    onfake().await;
+                    self.acting = None;
+                }
+                NightAction::Fake(_) => {
+                    debug!("skipping action of {:?}", role.read().await);
                 }
+                NightAction::ViewMarks => unreachable!(),
             }
         }
 
+        if let Some((_, _, ends)) = turn {
+            Self::finish_night_turn(ends).await;
+        }
+
         Ok(())
     }
 
+    /// Lets every player look at the mark in front of them.
+    #[instrument(level = "trace")]
+    async fn view_marks(&self) {
+        join_all(self.players.keys().map(|player| async move {
+            let mark = self.mark_of(player);
+            if let Err(e) = self
+                .show_mark(player, RoleTarget::Player(player.clone()), &mark)
+                .await
+            {
+                warn!("could not show {} their mark: {}", player, e);
+            }
+        }))
+        .await;
+    }
+
+    /// Waits out the rest of a night turn so that it lasts as long as configured.
+    #[instrument(level = "trace")]
+    async fn finish_night_turn(ends: Option<Instant>) {
+        if let Some(ends) = ends {
+            sleep_until(ends).await;
+        }
+    }
+
     #[instrument(level = "trace")]
     pub fn peek_next_night_action(&self) -> Option<&ActionPriority> {
         self.nightactions.first_key_value().map(|(k, _)| k)
     }
 
+    /// Wakes everyone up: turns the revealed cards face up for all players and lets every
+    /// artifact holder look at their artifact.
+    #[instrument(level = "trace")]
+    pub async fn start_day(&mut self) -> Result<(), GameError> {
+        if !self.nightactions.is_empty() || self.votes.is_some() {
+            (Err(GameError::WrongCmdOrder))?
+        }
+
+        self.announce_time(&ONUWTime::Day).await;
+
+        for target in self.face_up.iter() {
+            let role = self.players[target].read().await;
+            join_all(self.players.keys().map(|player| {
+                self.show_face_up(player, RoleTarget::Player(target.clone()), role.as_ref())
+            }))
+            .await;
+        }
+
+        for i in self.face_up_center.iter() {
+            let role = self.centerroles[*i].read().await;
+            join_all(
+                self.players
+                    .keys()
+                    .map(|player| self.show_face_up(player, RoleTarget::Center(*i), role.as_ref())),
+            )
+            .await;
+        }
+
+        for (player, artifact) in self.artifacts.iter() {
+            self.observe(
+                player,
+                Observation::Artifact {
+                    target: ObservedTarget::Player(player.id()),
+                    artifact: artifact.to_string(),
+                },
+            );
+
+            if let Err(e) = player
+                .show_artifact(RoleTarget::Player(player.clone()), artifact)
+                .await
+            {
+                warn!("could not show {} their artifact: {}", player, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn players(&self) -> &BTreeMap<GamePlayer, GameRole> {
+        &self.players
+    }
+
+    pub(crate) fn players_by_type(&self) -> &HashMap<RoleType, BTreeSet<GamePlayer>> {
+        &self.players_by_type
+    }
+
+    pub(crate) fn centerroles(&self) -> &Vec<GameRole> {
+        &self.centerroles
+    }
+
+    /// The players in the order they sit around the table, each to the right of the one before.
+    /// The first player sits to the right of the last.
+    pub fn seats(&self) -> &[GamePlayer] {
+        &self.seats
+    }
+
+    /// The seat `player` sits in, counting from 0.
+    #[instrument(level = "trace")]
+    pub fn seat_of(&self, player: &GamePlayer) -> Option<usize> {
+        self.seats.iter().position(|p| p == player)
+    }
+
+    /// The player sitting `offset` seats away from `player`, counting to the right for positive
+    /// offsets and to the left for negative ones.
+    #[instrument(level = "trace")]
+    pub fn seat_at_offset(&self, player: &GamePlayer, offset: isize) -> Option<&GamePlayer> {
+        let seat = self.seat_of(player)?;
+        let count = self.seats.len() as isize;
+        Some(&self.seats[(seat as isize + offset).rem_euclid(count) as usize])
+    }
+
+    /// The player sitting directly to the left of `player`.
+    #[instrument(level = "trace")]
+    pub fn left_of(&self, player: &GamePlayer) -> Option<&GamePlayer> {
+        self.seat_at_offset(player, -1)
+    }
+
+    /// The player sitting directly to the right of `player`.
+    #[instrument(level = "trace")]
+    pub fn right_of(&self, player: &GamePlayer) -> Option<&GamePlayer> {
+        self.seat_at_offset(player, 1)
+    }
+
+    /// The players sitting directly to the left and right of `player`.
+    #[instrument(level = "trace")]
+    pub fn neighbours(&self, player: &GamePlayer) -> Vec<&GamePlayer> {
+        [self.left_of(player), self.right_of(player)]
+            .into_iter()
+            .flatten()
+            .filter(|p| *p != player)
+            .unique()
+            .collect()
+    }
+
+    pub(crate) fn artifacts(&self) -> &BTreeMap<GamePlayer, Artifact> {
+        &self.artifacts
+    }
+
+    /// Places `artifact` on `player`'s card. The holder learns what it is at daybreak.
+    /// `calling_role` stands in for the card being acted with, which may be `player`'s.
+    #[instrument(level = "trace")]
+    pub(crate) fn place_artifact(
+        &mut self,
+        calling_role: &dyn Role,
+        player: &GamePlayer,
+        artifact: Artifact,
+    ) {
+        if let Some(role) = artifact.role() {
+            let old_type = match self.players[player].try_read() {
+                Ok(role) => role.role_type(),
+                Err(_) => calling_role.role_type(),
+            };
+            self.update_player_type(player, &old_type, role.role_type());
+        }
+
+        self.artifacts.insert(player.clone(), artifact);
+    }
+
+    /// Draws one of `choices` at random and records the result under `purpose`.
+    #[instrument(level = "trace", skip(choices))]
+    pub(crate) fn draw_random<T: Clone + Display>(
+        &mut self,
+        purpose: &str,
+        choices: &[T],
+    ) -> Option<T> {
+        let result = choices.choose(&mut self.rng)?.clone();
+
+        self.random_draws.push(RandomDraw {
+            purpose: purpose.to_string(),
+            result: result.to_string(),
+        });
+
+        Some(result)
+    }
+
+    /// The results drawn so far under `purpose`, oldest first.
+    pub(crate) fn random_draws_for<'a>(
+        &'a self,
+        purpose: &'a str,
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        self.random_draws
+            .iter()
+            .filter(move |d| d.purpose == purpose)
+            .map(|d| d.result.as_str())
+    }
+
+    pub(crate) fn marks(&self) -> &BTreeMap<GamePlayer, Mark> {
+        &self.marks
+    }
+
+    pub(crate) fn mark_of(&self, player: &GamePlayer) -> Mark {
+        self.marks.get(player).copied().unwrap_or_default()
+    }
+
+    /// Replaces `player`'s mark, moving them to the team the new mark puts them on.
+    #[instrument(level = "trace")]
+    pub(crate) fn place_mark(&mut self, player: &GamePlayer, mark: Mark) {
+        let old_type = self
+            .players_by_type
+            .iter()
+            .find(|(_, players)| players.contains(player))
+            .map(|(role_type, _)| role_type.clone());
+
+        if let Some(old_type) = old_type {
+            let new_type = match mark.role() {
+                Some(role) => {
+                    self.unmarked_types
+                        .entry(player.clone())
+                        .or_insert_with(|| old_type.clone());
+                    Some(role.role_type())
+                }
+                None => self.unmarked_types.remove(player),
+            };
+
+            if let Some(new_type) = new_type {
+                self.update_player_type(player, &old_type, new_type);
+            }
+        }
+
+        self.marks.insert(player.clone(), mark);
+    }
+
+    /// Shields `player`'s card, so that later night actions can neither move nor view it.
+    #[instrument(level = "trace")]
+    pub(crate) fn shield(&mut self, player: &GamePlayer) {
+        self.shields.insert(player.clone());
+    }
+
+    pub(crate) fn is_shielded(&self, player: &GamePlayer) -> bool {
+        self.shields.contains(player)
+    }
+
+    /// Leaves `player`'s card face up for everyone to see once the day starts.
+    #[instrument(level = "trace")]
+    pub(crate) fn flip_face_up(&mut self, player: &GamePlayer) {
+        if !self.face_up.contains(player) {
+            self.face_up.push(player.clone());
+        }
+    }
+
+    /// Leaves the center card at index `i` face up for everyone to see once the day starts.
+    #[instrument(level = "trace")]
+    pub(crate) fn flip_center_face_up(&mut self, i: usize) {
+        if !self.face_up_center.contains(&i) {
+            self.face_up_center.push(i);
+        }
+    }
+
+    /// The role `player` wins and acts with after the vote: the role the vote turned them into,
+    /// then the role of their mark or artifact, if either has one, and their card otherwise.
+    #[instrument(level = "trace")]
+    pub(crate) fn win_role(&self, player: &GamePlayer) -> GameRole {
+        if let Some(role) = self.turned.get(player) {
+            return role.clone();
+        }
+
+        let role = self
+            .mark_of(player)
+            .role()
+            .or_else(|| self.artifacts.get(player).and_then(Artifact::role));
+
+        match role {
+            Some(role) => Arc::new(RwLock::new(role)),
+            None => self.players[player].clone(),
+        }
+    }
+
+    pub(crate) fn werewolf_card(&self) -> Option<&GameRole> {
+        self.werewolf_card.as_ref()
+    }
+
+    pub(crate) fn set_werewolf_card(&mut self, role: GameRole) {
+        self.werewolf_card = Some(role);
+    }
+
     #[instrument(level = "trace")]
     pub(crate) fn all_other_players(&self, player: &GamePlayer) -> Vec<&GamePlayer> {
         self.players
             .iter()
-            .filter_map(|(p, _)| {
-                if p.as_ref() == player.as_ref() {
-                    None
-                } else {
-                    Some(p)
-                }
-            })
+            .filter_map(|(p, _)| if p == player { None } else { Some(p) })
             .collect()
     }
 
     #[instrument(level = "trace")]
     pub async fn show_all_roles(&self) {
         let fut = join_all(self.players.iter().map(|(player, role)| async {
-            player
-                .show_role(
-                    RoleTarget::Player(player.clone()),
-                    role.read().await.as_ref(),
-                )
-                .await;
+            self.show_role(
+                player,
+                RoleTarget::Player(player.clone()),
+                role.read().await.as_ref(),
+            )
+            .await;
         }));
         fut.await;
     }
 
+    /// Shows `role` to `player` and remembers it as part of what they know. This counts as
+    /// `player` viewing the card.
+    #[instrument(level = "trace", skip(role))]
+    pub(crate) async fn show_role(
+        &self,
+        player: &GamePlayer,
+        target: RoleTarget,
+        role: &dyn Role,
+    ) -> Result<(), PlayerInterfaceError> {
+        self.log_night_event(NightEvent::Viewed {
+            player: player.id(),
+            target: (&target).into(),
+        });
+
+        self.show_role_holder(player, target, role).await
+    }
+
+    /// Shows `player` that `target` holds `role` without them looking at the card, like the
+    /// Masons seeing each other.
+    #[instrument(level = "trace", skip(role))]
+    pub(crate) async fn show_role_holder(
+        &self,
+        player: &GamePlayer,
+        target: RoleTarget,
+        role: &dyn Role,
+    ) -> Result<(), PlayerInterfaceError> {
+        self.observe(
+            player,
+            Observation::Role {
+                target: (&target).into(),
+                role: role.effective_id(),
+            },
+        );
+
+        player.show_role(target, role).await
+    }
+
+    /// Shows a face up card to `player` and remembers it as part of what they know.
+    #[instrument(level = "trace", skip(role))]
+    async fn show_face_up(
+        &self,
+        player: &GamePlayer,
+        target: RoleTarget,
+        role: &dyn Role,
+    ) -> Result<(), PlayerInterfaceError> {
+        self.observe(
+            player,
+            Observation::Role {
+                target: (&target).into(),
+                role: role.effective_id(),
+            },
+        );
+
+        player.show_face_up(target, role).await
+    }
+
+    /// Shows `mark` to `player` and remembers it as part of what they know.
+    #[instrument(level = "trace")]
+    pub(crate) async fn show_mark(
+        &self,
+        player: &GamePlayer,
+        target: RoleTarget,
+        mark: &Mark,
+    ) -> Result<(), PlayerInterfaceError> {
+        self.observe(
+            player,
+            Observation::Mark {
+                target: (&target).into(),
+                mark: mark.to_string(),
+            },
+        );
+
+        player.show_mark(target, mark).await
+    }
+
+    /// Gives `player` a random instruction and remembers it as part of what they know.
+    #[instrument(level = "trace")]
+    pub(crate) async fn show_instruction(
+        &self,
+        player: &GamePlayer,
+        instruction: &str,
+    ) -> Result<(), PlayerInterfaceError> {
+        self.observe(
+            player,
+            Observation::Instruction {
+                instruction: instruction.to_string(),
+            },
+        );
+
+        player.show_instruction(instruction).await
+    }
+
+    /// Shows `role_type` to `player` and remembers it as part of what they know.
+    #[instrument(level = "trace")]
+    pub(crate) async fn show_role_type(
+        &self,
+        player: &GamePlayer,
+        target: RoleTarget,
+        role_type: &RoleType,
+    ) -> Result<(), PlayerInterfaceError> {
+        self.observe(
+            player,
+            Observation::RoleType {
+                target: (&target).into(),
+                role_type: role_type.to_string(),
+            },
+        );
+
+        player.show_role_type(target, role_type).await
+    }
+
+    /// Shows `player` that `target` moved or viewed a card tonight.
+    #[instrument(level = "trace")]
+    pub(crate) async fn show_acted(
+        &self,
+        player: &GamePlayer,
+        target: RoleTarget,
+    ) -> Result<(), PlayerInterfaceError> {
+        self.observe(
+            player,
+            Observation::Acted {
+                target: (&target).into(),
+            },
+        );
+
+        player.show_acted(target).await
+    }
+
+    /// Shows `player` that `pointer` pointed at `target` and remembers it as part of what they
+    /// know.
+    #[instrument(level = "trace")]
+    pub(crate) async fn show_pointed(
+        &self,
+        player: &GamePlayer,
+        pointer: RoleTarget,
+        target: RoleTarget,
+    ) -> Result<(), PlayerInterfaceError> {
+        self.observe(
+            player,
+            Observation::Pointed {
+                pointer: (&pointer).into(),
+                target: (&target).into(),
+            },
+        );
+
+        player.show_pointed(pointer, target).await
+    }
+
+    /// The cards moved or viewed so far tonight, oldest first.
+    pub(crate) fn night_events(&self) -> Vec<NightEvent> {
+        self.night_events.lock().unwrap().clone()
+    }
+
+    fn log_night_event(&self, event: NightEvent) {
+        self.night_events.lock().unwrap().push(event);
+    }
+
+    fn observe(&self, player: &GamePlayer, observation: Observation) {
+        self.observations
+            .lock()
+            .unwrap()
+            .entry(player.clone())
+            .or_default()
+            .push(observation);
+    }
+
     #[instrument(level = "trace")]
     pub(crate) async fn change_role(&mut self, target: &RoleTarget, role: &GameRole) {
+        if let Some(player) = &self.acting {
+            self.log_night_event(NightEvent::Moved {
+                player: player.id(),
+                target: target.into(),
+            });
+        }
+
         match target {
             RoleTarget::Player(player) => {
                 self.players
@@ -236,46 +826,59 @@ impl ONUWGame {
         old_type: &RoleType,
         new_type: RoleType,
     ) {
-        self.players_by_type
-            .get_mut(old_type)
-            .unwrap()
-            .remove(target);
+        if let Some(players) = self.players_by_type.get_mut(old_type) {
+            players.remove(target);
+        }
 
         self.players_by_type
             .entry(new_type)
             .and_modify(|e| {
                 e.insert(target.clone());
             })
-            .or_insert_with(|| HashSet::from_iter([target.clone()].into_iter()));
+            .or_insert_with(|| BTreeSet::from_iter([target.clone()].into_iter()));
     }
 
     #[instrument(level = "trace")]
     pub async fn send_handshake(&self) {
-        let roles = stream::iter(self.centerroles.iter())
-            .then(|i| async move { i.read().await.id() })
-            .chain(stream::iter(self.players.values()).then(|r| async { r.read().await.id() }))
-            .collect::<Vec<_>>()
-            .await
-            .into_iter()
-            .counts();
+        let mut role_ids = Vec::new();
+        for role in self
+            .centerroles
+            .iter()
+            .chain(self.werewolf_card.iter())
+            .chain(self.players.values())
+        {
+            role_ids.push(role.read().await.id());
+        }
+        let roles: BTreeMap<_, _> = role_ids.into_iter().counts().into_iter().collect();
+        let seats: Vec<_> = self.seats.iter().collect();
 
-        join_all(
-            self.players()
-                .iter()
-                .map(|(p, _)| async { p.handshake(&self.all_other_players(p), &roles).await }),
-        )
+        join_all(self.players().iter().map(|(p, _)| async {
+            p.handshake(&self.all_other_players(p), &seats, &roles)
+                .await
+        }))
         .await;
     }
 
     #[instrument(level = "trace")]
-    pub async fn send_message_to_players(&self, message: Message) {
+    pub async fn send_message_to_players(&mut self, message: Message) {
+        if self
+            .artifacts
+            .get(&message.sender)
+            .is_some_and(Artifact::mutes)
+        {
+            warn!("{} is muted, dropping their message", message.sender);
+            return;
+        }
+
         join_all(
             self.players()
                 .iter()
-                .filter(|(p, _)| p.as_ref() != message.sender.as_ref())
+                .filter(|(p, _)| **p != message.sender)
                 .map(|(p, _)| p.receive_message(&message)),
         )
         .await;
+
+        self.messages.push(message);
     }
 
     #[instrument(level = "trace")]
@@ -313,11 +916,21 @@ impl ONUWGame {
             (Err(GameError::WrongCmdOrder))?
         }
 
+        let mut protected = BTreeSet::new();
+        for p in self.players.keys() {
+            protected.extend(self.win_role(p).read().await.protect_from_vote(
+                self,
+                p,
+                self.votes.as_ref().unwrap(),
+            ));
+        }
+
         self.dead = Some(
             self.votes
                 .as_ref()
                 .unwrap()
                 .values()
+                .filter(|v| !protected.contains(*v))
                 .cloned()
                 .counts()
                 .into_iter()
@@ -338,27 +951,43 @@ impl ONUWGame {
             .cloned()
             .collect::<Vec<_>>();
 
-        let actions: Vec<_> = stream::iter(self.players.iter())
-            .then(|(p, r)| async {
-                stream::iter(r.read().await.after_vote(
-                    self,
-                    p,
-                    self.votes.as_ref().unwrap(),
-                    &dead_vec,
-                ))
-            })
-            .flatten()
-            .collect()
-            .await;
+        let mut actions = Vec::new();
+        for p in self.players.keys() {
+            actions.append(&mut self.win_role(p).read().await.after_vote(
+                self,
+                p,
+                self.votes.as_ref().unwrap(),
+                &dead_vec,
+            ));
+        }
 
         for action in actions {
             match action {
                 ONUWGameVoteAction::Kill(p) => {
                     self.dead.as_mut().unwrap().insert(p);
                 }
+                ONUWGameVoteAction::Turn(p, role) => {
+                    let old_type = self.win_role(&p).read().await.role_type();
+                    self.update_player_type(&p, &old_type, role.role_type());
+                    self.turned.insert(p, Arc::new(RwLock::new(role)));
+                }
             }
         }
 
+        // lovers die together
+        let lovers: Vec<_> = self
+            .marks
+            .iter()
+            .filter(|(_, m)| **m == Mark::Love)
+            .map(|(p, _)| p.clone())
+            .collect();
+        if lovers
+            .iter()
+            .any(|p| self.dead.as_ref().unwrap().contains(p))
+        {
+            self.dead.as_mut().unwrap().extend(lovers);
+        }
+
         debug!("dead:\n{:#?}", self.dead.as_ref().unwrap());
 
         let dead_with_roles: Vec<_> = self
@@ -366,42 +995,222 @@ impl ONUWGame {
             .as_ref()
             .unwrap()
             .iter()
-            .map(|d| (d.clone(), self.players.get(d).unwrap().clone()))
+            .map(|d| (d.clone(), self.win_role(d)))
             .collect();
 
-        self.winners = Some(
-            stream::iter(self.players.iter())
-                .filter_map(|(player, role)| async {
-                    let win = role
-                        .read()
-                        .await
-                        .win_condition(self, player, &dead_with_roles);
+        let mut dead_teams = Vec::new();
+        for (player, role) in dead_with_roles.iter() {
+            dead_teams.push((player.clone(), role.read().await.team()));
+        }
 
-                    if win {
-                        Some(player.clone())
-                    } else {
-                        None
-                    }
-                })
-                .collect()
-                .await,
-        );
+        let mut winners = BTreeSet::new();
+        for player in self.players.keys() {
+            let role = self.win_role(player);
+            let role = role.read().await;
+
+            let won = match self.mark_of(player) {
+                // traitors only win if someone else on their team dies
+                Mark::Traitor => dead_teams
+                    .iter()
+                    .any(|(p, team)| p != player && *team == role.team()),
+                _ => role.win_condition(self, player, &dead_with_roles),
+            };
+
+            // voting for a diseased player keeps you from winning
+            let infected = self.mark_of(&self.votes.as_ref().unwrap()[player]) == Mark::Disease;
 
-        stream::iter(self.players.iter())
-            .for_each(|(player, role)| async {
-                player
-                    .show_win(
-                        role.read()
-                            .await
-                            .win_condition(self, player, &dead_with_roles),
+            if won && !infected {
+                winners.insert(player.clone());
+            }
+        }
+
+        let prevented = {
+            let mut winner_roles = Vec::new();
+            for winner in winners.iter() {
+                winner_roles.push((winner, self.win_role(winner)));
+            }
+            let mut winner_guards = Vec::new();
+            for (winner, role) in winner_roles.iter() {
+                winner_guards.push((*winner, role.read().await));
+            }
+            let winners_with_roles: Vec<_> = winner_guards
+                .iter()
+                .map(|(p, r)| (*p, r.as_ref()))
+                .collect();
+
+            let mut prevented = BTreeSet::new();
+            for player in self.players.keys() {
+                let role = self.win_role(player);
+                let role = role.read().await;
+                prevented.extend(
+                    role.prevent_win(
+                        self,
+                        player,
+                        &winners_with_roles,
+                        self.votes.as_ref().unwrap(),
                     )
-                    .await;
-            })
-            .await;
+                    .into_iter()
+                    .cloned(),
+                );
+            }
+            prevented
+        };
+
+        winners.retain(|p| !prevented.contains(p));
+
+        self.winners = Some(winners);
+
+        let dead: Vec<_> = self.dead.as_ref().unwrap().iter().cloned().collect();
+        let winners: Vec<_> = self.winners.as_ref().unwrap().iter().cloned().collect();
+        self.announce_time(&ONUWTime::End {
+            dead: &dead,
+            winners: &winners,
+        })
+        .await;
+
+        let reveal = self.reveal().await.unwrap();
+        join_all(
+            self.players
+                .keys()
+                .map(|player| player.show_reveal(&reveal)),
+        )
+        .await;
+
+        join_all(
+            self.players
+                .keys()
+                .map(|player| player.show_win(winners.contains(player))),
+        )
+        .await;
 
         Ok(())
     }
 
+    /// Flips every card face up. Only available once the winners are known.
+    #[instrument(level = "trace")]
+    pub async fn reveal(&self) -> Option<Reveal> {
+        let winners = self.winners.as_ref()?;
+        let snapshot = self.snapshot().await;
+
+        let winning_teams = snapshot
+            .players
+            .iter()
+            .filter(|p| winners.iter().any(|w| w.id() == p.id))
+            .map(|p| p.team.clone())
+            .sorted()
+            .dedup()
+            .collect();
+
+        Some(Reveal {
+            players: snapshot.players,
+            center: snapshot.center,
+            werewolf_card: snapshot.werewolf_card,
+            dead: snapshot.dead.unwrap_or_default(),
+            winners: snapshot.winners.unwrap_or_default(),
+            winning_teams,
+        })
+    }
+
+    /// Captures the full state of the game, including every hidden card.
+    #[instrument(level = "trace")]
+    pub async fn snapshot(&self) -> GameSnapshot {
+        let mut players = Vec::new();
+        for (player, role) in self.players.iter() {
+            let role = role.read().await;
+            players.push(PlayerSnapshot {
+                id: player.id(),
+                name: player.name().to_string(),
+                seat: self.seat_of(player).unwrap(),
+                dealt_role: self.dealt_roles[player].clone(),
+                role: role.verbose_id(),
+                copy_chain: copy_chain(role.as_ref())
+                    .iter()
+                    .skip(1)
+                    .map(|r| r.id())
+                    .collect(),
+                role_type: role.role_type().to_string(),
+                team: self.win_role(player).read().await.team(),
+                artifact: self.artifacts.get(player).map(Artifact::to_string),
+                face_up: self.face_up.contains(player),
+                shielded: self.shields.contains(player),
+                mark: self.mark_of(player).to_string(),
+            });
+        }
+        players.sort_by_key(|p| p.id);
+
+        let mut center = Vec::new();
+        for (i, (role, dealt_role)) in self
+            .centerroles
+            .iter()
+            .zip(self.dealt_centerroles.iter())
+            .enumerate()
+        {
+            let role = role.read().await;
+            center.push(CenterSnapshot {
+                dealt_role: dealt_role.clone(),
+                role: role.verbose_id(),
+                copy_chain: copy_chain(role.as_ref())
+                    .iter()
+                    .skip(1)
+                    .map(|r| r.id())
+                    .collect(),
+                face_up: self.face_up_center.contains(&i),
+            });
+        }
+
+        let werewolf_card = match &self.werewolf_card {
+            Some(role) => Some(CenterSnapshot {
+                dealt_role: Werewolf::new().id(),
+                role: role.read().await.verbose_id(),
+                copy_chain: Vec::new(),
+                face_up: false,
+            }),
+            None => None,
+        };
+
+        GameSnapshot {
+            players,
+            center,
+            werewolf_card,
+            pending_night_actions: self.nightactions.keys().cloned().collect(),
+            votes: self.vote_ids(),
+            dead: self.dead.as_ref().map(Self::ids),
+            winners: self.winners.as_ref().map(Self::ids),
+            seed: self.seed,
+            random_draws: self.random_draws.clone(),
+            night_events: self.night_events(),
+        }
+    }
+
+    /// Captures only what `player` has legitimately learned so far.
+    #[instrument(level = "trace")]
+    pub fn player_view(&self, player: &GamePlayer) -> Option<PlayerView> {
+        Some(PlayerView {
+            id: player.id(),
+            name: player.name().to_string(),
+            dealt_role: self.dealt_roles.get(player)?.clone(),
+            observations: self
+                .observations
+                .lock()
+                .unwrap()
+                .get(player)
+                .cloned()
+                .unwrap_or_default(),
+            messages: self.messages.iter().map(MessageView::from).collect(),
+            votes: self.vote_ids(),
+        })
+    }
+
+    fn vote_ids(&self) -> Option<Vec<(PlayerId, PlayerId)>> {
+        self.votes
+            .as_ref()
+            .map(|votes| votes.iter().map(|(p, v)| (p.id(), v.id())).collect())
+    }
+
+    fn ids(players: &BTreeSet<GamePlayer>) -> Vec<PlayerId> {
+        players.iter().map(GamePlayer::id).collect()
+    }
+
     #[instrument(level = "trace")]
     async fn announce_time<'a>(&self, time: &'a ONUWTime<'a>) {
         join_all(self.players().iter().map(|(p, _)| p.show_time(time))).await;
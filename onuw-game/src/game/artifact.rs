@@ -0,0 +1,52 @@
+use crate::role::{
+    roles::{hunter::Hunter, tanner::Tanner, villager::Villager, werewolf::Werewolf},
+    Role,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// A token the Curator can place on a player's card.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Artifact {
+    ClawOfTheWerewolf,
+    BrandOfTheVillager,
+    CudgelOfTheTanner,
+    BowOfTheHunter,
+    VoidOfNothingness,
+    MaskOfMuting,
+    ShroudOfShame,
+}
+
+impl Artifact {
+    pub const ALL: [Artifact; 7] = [
+        Artifact::ClawOfTheWerewolf,
+        Artifact::BrandOfTheVillager,
+        Artifact::CudgelOfTheTanner,
+        Artifact::BowOfTheHunter,
+        Artifact::VoidOfNothingness,
+        Artifact::MaskOfMuting,
+        Artifact::ShroudOfShame,
+    ];
+
+    /// The role whose team and powers the holder takes on instead of their card's, if any.
+    pub fn role(&self) -> Option<Box<dyn Role>> {
+        match self {
+            Artifact::ClawOfTheWerewolf => Some(Box::new(Werewolf::new())),
+            Artifact::BrandOfTheVillager => Some(Box::new(Villager::new())),
+            Artifact::CudgelOfTheTanner => Some(Box::new(Tanner::new())),
+            Artifact::BowOfTheHunter => Some(Box::new(Hunter::new())),
+            Artifact::VoidOfNothingness | Artifact::MaskOfMuting | Artifact::ShroudOfShame => None,
+        }
+    }
+
+    /// Whether the holder is kept from sending messages to the other players.
+    pub fn mutes(&self) -> bool {
+        matches!(self, Artifact::MaskOfMuting)
+    }
+}
+
+impl Display for Artifact {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
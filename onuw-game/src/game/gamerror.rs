@@ -1,9 +1,19 @@
+use super::options::MAX_CENTER_COUNT;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum GameError {
-    #[error("Excepted {} Roles due to having {players} players, but got {roles} Roles instead.", players + 3)]
-    InvalidRoleCount { roles: usize, players: usize },
+    #[error("Excepted {} Roles due to having {players} players and {center} center cards, but got {roles} Roles instead.", players + center)]
+    InvalidRoleCount {
+        roles: usize,
+        players: usize,
+        center: usize,
+    },
+    #[error(
+        "Can't have {center} center cards, the maximum is {}.",
+        MAX_CENTER_COUNT
+    )]
+    InvalidCenterCount { center: usize },
     #[error("No more night actions")]
     NoMoreNightActions,
     #[error("Can't caculate dead players without votes!")]
@@ -0,0 +1,72 @@
+use crate::role::{
+    roles::{alien::Alien, vampire::Vampire},
+    roletype::RoleType,
+    Role,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The priority of the step at the end of night in which every player looks at their mark.
+pub(crate) const VIEW_MARKS_PRIORITY: &str = "1Z";
+
+/// A mark token in front of a player. Marks stay with the player when cards are swapped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Mark {
+    #[default]
+    Clarity,
+    Love,
+    Traitor,
+    Disease,
+    Fear,
+    Bat,
+    Vampire,
+    Assassin,
+    /// Left by the aliens on a player they turned into one of them.
+    Alien,
+}
+
+impl Mark {
+    /// The role whose team the holder joins instead of their card's, if any.
+    pub fn role(&self) -> Option<Box<dyn Role>> {
+        match self {
+            Mark::Vampire => Some(Box::new(Vampire::new())),
+            Mark::Alien => Some(Box::new(Alien::new())),
+            Mark::Clarity
+            | Mark::Love
+            | Mark::Traitor
+            | Mark::Disease
+            | Mark::Fear
+            | Mark::Bat
+            | Mark::Assassin => None,
+        }
+    }
+
+    /// Whether the holder is kept from performing their night action.
+    pub fn blocks_night_action(&self) -> bool {
+        matches!(self, Mark::Fear)
+    }
+
+    /// Whether `role` can move marks around, so that players need to look at theirs.
+    pub(crate) fn placed_by(role: &dyn Role) -> bool {
+        matches!(role.role_type(), RoleType::Vampire | RoleType::Alien)
+            || matches!(
+                role.id().as_str(),
+                "Renfield"
+                    | "Diseased"
+                    | "Cupid"
+                    | "Instigator"
+                    | "Priest"
+                    | "Assassin"
+                    | "ApprenticeAssassin"
+                    | "Pickpocket"
+                    | "Gremlin"
+                    | "Oracle"
+            )
+    }
+}
+
+impl Display for Mark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -4,4 +4,6 @@ use super::{GamePlayer, GameRole};
 pub enum NightAction {
     Real(GameRole, GamePlayer),
     Fake(GameRole),
+    /// Every player looks at the mark in front of them.
+    ViewMarks,
 }
@@ -1,12 +1,70 @@
 use derive_getters::Getters;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
 
-#[derive(Default, Debug, Serialize, Deserialize, Getters)]
+pub const DEFAULT_CENTER_COUNT: usize = 3;
+pub const MAX_CENTER_COUNT: usize = 5;
+pub const DEFAULT_NIGHT_TURN_LENGTH: Duration = Duration::from_secs(10);
+
+/// How long a night turn lasts, whether the role is held by a player or sits in the center.
+/// Every player holding the role gets this long for their action and is cut off after that.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NightTurnLength {
+    /// The turn ends as soon as the action is done. This gives away which roles are in the
+    /// center, so it is only meant for tests and games without human players.
+    Instant,
+    Fixed(Duration),
+    /// A length picked uniformly between the two bounds each turn.
+    Random {
+        min: Duration,
+        max: Duration,
+    },
+}
+
+impl Default for NightTurnLength {
+    fn default() -> Self {
+        Self::Fixed(DEFAULT_NIGHT_TURN_LENGTH)
+    }
+}
+
+impl NightTurnLength {
+    /// The length of one turn. Random lengths are drawn from `rng`, so that a game's seed
+    /// also replays its pacing.
+    pub fn sample(&self, rng: &mut impl Rng) -> Duration {
+        match self {
+            Self::Instant => Duration::ZERO,
+            Self::Fixed(length) => *length,
+            Self::Random { min, max } => rng.gen_range(*min.min(max)..=*min.max(max)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Getters)]
 pub struct Options {
     lone_wolf: bool,
+    center_count: usize,
+    night_turn_length: NightTurnLength,
+    role_night_turn_lengths: HashMap<String, NightTurnLength>,
+    seed: Option<u64>,
+    random_seating: bool,
     debug_set_roles: bool,
 }
 
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            lone_wolf: false,
+            center_count: DEFAULT_CENTER_COUNT,
+            night_turn_length: NightTurnLength::default(),
+            role_night_turn_lengths: HashMap::new(),
+            seed: None,
+            random_seating: false,
+            debug_set_roles: false,
+        }
+    }
+}
+
 impl Options {
     pub fn new() -> Self {
         Self::default()
@@ -17,6 +75,49 @@ impl Options {
         self
     }
 
+    /// Sets how many cards are placed in the center, from 0 to [`MAX_CENTER_COUNT`].
+    pub fn with_center_count(mut self, center_count: usize) -> Self {
+        self.center_count = center_count;
+        self
+    }
+
+    /// Sets the length of every night turn. Turns of roles in the center take just as long as
+    /// real ones, so nobody can tell which roles are in the middle.
+    pub fn with_night_turn_length(mut self, length: NightTurnLength) -> Self {
+        self.night_turn_length = length;
+        self
+    }
+
+    /// Overrides the night turn length for a single role id.
+    pub fn with_role_night_turn_length(
+        mut self,
+        role_id: impl Into<String>,
+        length: NightTurnLength,
+    ) -> Self {
+        self.role_night_turn_lengths.insert(role_id.into(), length);
+        self
+    }
+
+    /// Fixes the seed of the game's random number generator, so that the deal and every
+    /// random draw during the game can be replayed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Seats the players around the table in a random order drawn from the game's seed,
+    /// instead of the order they were passed to [`ONUWGame::new`](crate::game::ONUWGame::new).
+    pub fn with_random_seating(mut self) -> Self {
+        self.random_seating = true;
+        self
+    }
+
+    pub fn night_turn_length_for(&self, role_id: &str) -> &NightTurnLength {
+        self.role_night_turn_lengths
+            .get(role_id)
+            .unwrap_or(&self.night_turn_length)
+    }
+
     #[allow(unused)]
     pub(crate) fn debug_with_set_roles(mut self) -> Self {
         self.debug_set_roles = true;
@@ -0,0 +1,89 @@
+use crate::playerinterface::PlayerInterface;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    ops::Deref,
+    sync::Arc,
+};
+
+/// Identifies a player for the length of a game. The engine hands ids out in the order the
+/// players are passed to [`ONUWGame::new`](super::ONUWGame::new), so players may share a
+/// display name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct PlayerId(pub(crate) usize);
+
+impl Display for PlayerId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// A player taking part in a game. Players are compared, hashed and ordered by their
+/// [`PlayerId`] alone, their name is only for display.
+#[derive(Clone)]
+pub struct GamePlayer {
+    id: PlayerId,
+    interface: Arc<dyn PlayerInterface>,
+}
+
+impl GamePlayer {
+    pub(crate) fn new(id: PlayerId, interface: Arc<dyn PlayerInterface>) -> Self {
+        Self { id, interface }
+    }
+
+    pub fn id(&self) -> PlayerId {
+        self.id
+    }
+
+    pub fn interface(&self) -> &Arc<dyn PlayerInterface> {
+        &self.interface
+    }
+}
+
+impl Deref for GamePlayer {
+    type Target = dyn PlayerInterface;
+
+    fn deref(&self) -> &Self::Target {
+        self.interface.as_ref()
+    }
+}
+
+impl Display for GamePlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.interface.as_ref(), f)
+    }
+}
+
+impl Debug for GamePlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.interface.as_ref(), self.id)
+    }
+}
+
+impl PartialEq for GamePlayer {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for GamePlayer {}
+
+impl Hash for GamePlayer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+impl PartialOrd for GamePlayer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GamePlayer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
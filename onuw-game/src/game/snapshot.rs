@@ -0,0 +1,163 @@
+use super::PlayerId;
+use crate::{
+    playerinterface::{message::Message, roletarget::RoleTarget},
+    role::ActionPriority,
+};
+use serde::{Deserialize, Serialize};
+
+/// The complete state of a game at one point in time, including hidden information.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    pub players: Vec<PlayerSnapshot>,
+    pub center: Vec<CenterSnapshot>,
+    /// The extra werewolf card used by the Alpha Wolf, if it is in the game.
+    pub werewolf_card: Option<CenterSnapshot>,
+    pub pending_night_actions: Vec<ActionPriority>,
+    pub votes: Option<Vec<(PlayerId, PlayerId)>>,
+    pub dead: Option<Vec<PlayerId>>,
+    pub winners: Option<Vec<PlayerId>>,
+    /// The seed the game's random number generator was started with.
+    pub seed: u64,
+    pub random_draws: Vec<RandomDraw>,
+    pub night_events: Vec<NightEvent>,
+}
+
+/// A random result the game drew during the night, such as an alien instruction.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RandomDraw {
+    pub purpose: String,
+    pub result: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerSnapshot {
+    pub id: PlayerId,
+    pub name: String,
+    /// Where the player sits at the table, see [`ONUWGame::seats`](super::ONUWGame::seats).
+    pub seat: usize,
+    pub dealt_role: String,
+    pub role: String,
+    /// The roles this card copied, outermost first, e.g. `["Copycat", "Seer"]` for a
+    /// Doppelganger that copied a Copycat that copied a Seer.
+    pub copy_chain: Vec<String>,
+    pub role_type: String,
+    pub team: String,
+    pub artifact: Option<String>,
+    pub face_up: bool,
+    pub shielded: bool,
+    pub mark: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CenterSnapshot {
+    pub dealt_role: String,
+    pub role: String,
+    pub copy_chain: Vec<String>,
+    pub face_up: bool,
+}
+
+/// The final cards and outcome, shown to every player once the game is over.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reveal {
+    pub players: Vec<PlayerSnapshot>,
+    pub center: Vec<CenterSnapshot>,
+    /// The extra werewolf card used by the Alpha Wolf, if it is in the game.
+    pub werewolf_card: Option<CenterSnapshot>,
+    pub dead: Vec<PlayerId>,
+    pub winners: Vec<PlayerId>,
+    pub winning_teams: Vec<String>,
+}
+
+/// Everything a single player legitimately knows about the game.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerView {
+    pub id: PlayerId,
+    pub name: String,
+    pub dealt_role: String,
+    pub observations: Vec<Observation>,
+    pub messages: Vec<MessageView>,
+    pub votes: Option<Vec<(PlayerId, PlayerId)>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObservedTarget {
+    Player(PlayerId),
+    Center(usize),
+}
+
+impl From<&RoleTarget> for ObservedTarget {
+    fn from(value: &RoleTarget) -> Self {
+        match value {
+            RoleTarget::Player(p) => Self::Player(p.id()),
+            RoleTarget::Center(i) => Self::Center(*i),
+        }
+    }
+}
+
+/// Something a player was shown during the game.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Observation {
+    Role {
+        target: ObservedTarget,
+        role: String,
+    },
+    RoleType {
+        target: ObservedTarget,
+        role_type: String,
+    },
+    Artifact {
+        target: ObservedTarget,
+        artifact: String,
+    },
+    Mark {
+        target: ObservedTarget,
+        mark: String,
+    },
+    Instruction {
+        instruction: String,
+    },
+    Acted {
+        target: ObservedTarget,
+    },
+    Pointed {
+        pointer: ObservedTarget,
+        target: ObservedTarget,
+    },
+}
+
+/// A card being moved or viewed during the night, and by whom.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NightEvent {
+    Viewed {
+        player: PlayerId,
+        target: ObservedTarget,
+    },
+    Moved {
+        player: PlayerId,
+        target: ObservedTarget,
+    },
+}
+
+impl NightEvent {
+    /// The player who moved or viewed the card.
+    pub fn player(&self) -> PlayerId {
+        match self {
+            NightEvent::Viewed { player, .. } | NightEvent::Moved { player, .. } => *player,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageView {
+    pub sender: PlayerId,
+    pub text: String,
+}
+
+impl From<&Message> for MessageView {
+    fn from(value: &Message) -> Self {
+        Self {
+            sender: value.sender.id(),
+            text: value.mtype.to_string(),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use super::GamePlayer;
+use crate::role::Role;
 
 #[derive(Debug)]
 pub enum ONUWGameVoteAction {
     Kill(GamePlayer),
+    /// The player wins as the given role from now on, whatever card they hold.
+    Turn(GamePlayer, Box<dyn Role>),
 }
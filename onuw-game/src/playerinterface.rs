@@ -4,15 +4,13 @@ pub mod roletarget;
 
 use self::{error::PlayerInterfaceError, message::Message, roletarget::RoleTarget};
 use crate::{
-    game::time::ONUWTime,
+    game::{artifact::Artifact, mark::Mark, snapshot::Reveal, time::ONUWTime, GamePlayer},
     role::{roletype::RoleType, Role},
 };
 use async_trait::async_trait;
 use std::{
-    collections::HashMap,
+    collections::BTreeMap,
     fmt::{Debug, Display},
-    hash::Hash,
-    sync::Arc,
 };
 
 #[async_trait]
@@ -31,26 +29,67 @@ pub trait PlayerInterface: Send + Sync {
         role: &RoleType,
     ) -> Result<(), PlayerInterfaceError>;
 
+    /// Shows a card that lies face up for every player to see.
+    async fn show_face_up(
+        &self,
+        target: RoleTarget,
+        role: &dyn Role,
+    ) -> Result<(), PlayerInterfaceError>;
+
+    async fn show_artifact(
+        &self,
+        target: RoleTarget,
+        artifact: &Artifact,
+    ) -> Result<(), PlayerInterfaceError>;
+
+    async fn show_mark(&self, target: RoleTarget, mark: &Mark) -> Result<(), PlayerInterfaceError>;
+
+    /// Tells the player what the app randomly instructed them to do, e.g. as an alien.
+    async fn show_instruction(&self, instruction: &str) -> Result<(), PlayerInterfaceError>;
+
+    /// Tells the player that `target` moved or viewed a card tonight, e.g. as the Aura Seer.
+    async fn show_acted(&self, target: RoleTarget) -> Result<(), PlayerInterfaceError>;
+
+    /// Tells the player that `pointer` pointed at `target`, e.g. as the Empath.
+    async fn show_pointed(
+        &self,
+        pointer: RoleTarget,
+        target: RoleTarget,
+    ) -> Result<(), PlayerInterfaceError>;
+
     async fn choose_player<'a>(
         &self,
-        players: &'a [&'a Arc<dyn PlayerInterface>],
-    ) -> Result<Arc<dyn PlayerInterface>, PlayerInterfaceError>;
+        players: &'a [&'a GamePlayer],
+    ) -> Result<GamePlayer, PlayerInterfaceError>;
 
     async fn choose_bool(&self) -> Result<bool, PlayerInterfaceError>;
 
+    /// Asks the player to answer `question` by pointing at one of `players` with their eyes
+    /// closed, e.g. for the Empath.
+    async fn point_at<'a>(
+        &self,
+        question: &str,
+        players: &'a [&'a GamePlayer],
+    ) -> Result<GamePlayer, PlayerInterfaceError>;
+
     async fn choose_num(&self, choices: &[isize]) -> Result<isize, PlayerInterfaceError>;
 
     async fn receive_message(&self, message: &Message) -> Result<(), PlayerInterfaceError>;
 
+    /// Greets the player before the night with the other players, everyone's seats in the
+    /// order of [`ONUWGame::seats`](crate::game::ONUWGame::seats) and the roles in the game.
     async fn handshake<'a>(
         &self,
-        players: &'a [&'a Arc<dyn PlayerInterface>],
-        roles: &'a HashMap<String, usize>,
+        players: &'a [&'a GamePlayer],
+        seats: &'a [&'a GamePlayer],
+        roles: &'a BTreeMap<String, usize>,
     ) -> Result<(), PlayerInterfaceError>;
 
     async fn show_time(&self, time: &ONUWTime) -> Result<(), PlayerInterfaceError>;
 
     async fn show_win(&self, won_game: bool) -> Result<(), PlayerInterfaceError>;
+
+    async fn show_reveal(&self, reveal: &Reveal) -> Result<(), PlayerInterfaceError>;
 }
 
 impl Display for dyn PlayerInterface {
@@ -64,29 +103,3 @@ impl Debug for dyn PlayerInterface {
         (&self as &dyn Display).fmt(f)
     }
 }
-
-impl PartialEq for dyn PlayerInterface {
-    fn eq(&self, other: &Self) -> bool {
-        self.name() == other.name()
-    }
-}
-
-impl Eq for dyn PlayerInterface {}
-
-impl Hash for dyn PlayerInterface {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.name().hash(state)
-    }
-}
-
-impl PartialOrd for dyn PlayerInterface {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.name().cmp(other.name()))
-    }
-}
-
-impl Ord for dyn PlayerInterface {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
-    }
-}
@@ -1,4 +1,5 @@
 use crate::game::GamePlayer;
+use std::fmt::Display;
 
 #[derive(Clone, Debug)]
 pub struct Message {
@@ -29,3 +30,58 @@ pub enum QuestionType {
     DidRoleActionToOne(String, GamePlayer),
     DidRoleActionToTwo(String, GamePlayer, GamePlayer),
 }
+
+impl Display for MessageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessageType::Claim(claim) => write!(f, "claims {}", claim),
+            MessageType::ClaimNot(claim) => write!(f, "claims not {}", claim),
+            MessageType::Question(target, question) => {
+                write!(f, "asks {} {}", target.name(), question)
+            }
+        }
+    }
+}
+
+impl Display for ClaimType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClaimType::IsRole(role) => write!(f, "to be {}", role),
+            ClaimType::PerformedRoleActionToSelf(role) => {
+                write!(f, "to have done the {} action to themselves", role)
+            }
+            ClaimType::PerformedRoleActionToOne(role, one) => {
+                write!(f, "to have done the {} action to {}", role, one.name())
+            }
+            ClaimType::PerformedRoleActionToTwo(role, one, two) => write!(
+                f,
+                "to have done the {} action to {} and {}",
+                role,
+                one.name(),
+                two.name()
+            ),
+        }
+    }
+}
+
+impl Display for QuestionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestionType::WhatRole => write!(f, "what their role is"),
+            QuestionType::AreRole(role) => write!(f, "whether they are {}", role),
+            QuestionType::DidRoleActionToSelf(role) => {
+                write!(f, "whether they did the {} action to themselves", role)
+            }
+            QuestionType::DidRoleActionToOne(role, one) => {
+                write!(f, "whether they did the {} action to {}", role, one.name())
+            }
+            QuestionType::DidRoleActionToTwo(role, one, two) => write!(
+                f,
+                "whether they did the {} action to {} and {}",
+                role,
+                one.name(),
+                two.name()
+            ),
+        }
+    }
+}
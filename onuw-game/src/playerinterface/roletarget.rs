@@ -1,8 +1,7 @@
-use super::PlayerInterface;
-use std::sync::Arc;
+use crate::game::GamePlayer;
 
 #[derive(Debug)]
 pub enum RoleTarget {
-    Player(Arc<dyn PlayerInterface>),
+    Player(GamePlayer),
     Center(usize),
 }
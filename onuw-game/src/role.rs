@@ -1,17 +1,16 @@
+pub mod pack;
 pub mod roles;
 pub mod roletype;
+pub mod script;
 
 use self::roletype::RoleType;
-use crate::{
-    game::{voteaction::ONUWGameVoteAction, GamePlayer, GameRole, ONUWGame},
-    playerinterface::PlayerInterface,
-};
+use crate::game::{voteaction::ONUWGameVoteAction, GamePlayer, GameRole, ONUWGame};
 use async_trait::async_trait;
 use dyn_clone::DynClone;
 use futures::Future;
 use std::{
     boxed::Box,
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt::{Debug, Display},
     hash::Hash,
     pin::Pin,
@@ -20,6 +19,22 @@ use tracing::instrument;
 
 pub type ActionPriority = String;
 
+/// What a Doppelganger does with the night actions of a role it copies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopyBehaviour {
+    /// Performs the copied actions right away, during the Doppelganger's own turn.
+    ActNow,
+    /// Wakes up again at the copied role's priorities, e.g. alongside the other werewolves.
+    ActLater,
+    /// Copies nothing at night. Anything the role does happens during the vote.
+    Passive,
+}
+
+/// How many roles deep a chain of copies can go, counting the copier. A Doppelganger that copied
+/// a Copycat that copied a Seer is three deep. Copying a longer chain copies nothing, so roles
+/// that copy each other cannot nest without end.
+pub const MAX_COPY_DEPTH: usize = 4;
+
 pub type ActionFn<R> = for<'a> fn(
     &'a mut R,
     &'a mut ONUWGame,
@@ -75,11 +90,34 @@ pub trait Role: Send + Sync + Debug + DynClone {
 
     fn role_type(&self) -> RoleType;
 
+    /// The team this role wins with, shown when the cards are revealed.
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        self.role_type().to_string()
+    }
+
     #[instrument(level = "trace")]
     fn priorities(&self) -> Vec<&ActionPriority> {
         Vec::new()
     }
 
+    /// The role this one copied during the night, for roles like the Doppelganger.
+    #[instrument(level = "trace")]
+    fn copied(&self) -> Option<&dyn Role> {
+        None
+    }
+
+    /// How a Doppelganger that copies this role uses its night actions. Roles without night
+    /// actions are passive, and the rest act right away unless they say otherwise.
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        if self.priorities().is_empty() {
+            CopyBehaviour::Passive
+        } else {
+            CopyBehaviour::ActNow
+        }
+    }
+
     #[instrument(level = "trace")]
     async fn action_at_priority(
         &mut self,
@@ -95,12 +133,24 @@ pub trait Role: Send + Sync + Debug + DynClone {
         &self,
         game: &ONUWGame,
         player: &GamePlayer,
-        votes: &HashMap<GamePlayer, GamePlayer>,
+        votes: &BTreeMap<GamePlayer, GamePlayer>,
         dead: &[GamePlayer],
     ) -> Vec<ONUWGameVoteAction> {
         Vec::new()
     }
 
+    /// Players who cannot be killed by the vote because of `player`'s role. The player with
+    /// the next most votes dies in their place.
+    #[instrument(level = "trace")]
+    fn protect_from_vote(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        votes: &BTreeMap<GamePlayer, GamePlayer>,
+    ) -> Vec<GamePlayer> {
+        Vec::new()
+    }
+
     fn win_condition(
         &self,
         game: &ONUWGame,
@@ -113,15 +163,35 @@ pub trait Role: Send + Sync + Debug + DynClone {
         &self,
         game: &ONUWGame,
         player: &GamePlayer,
-        #[allow(unused)] winners: &'a [(&'a dyn PlayerInterface, &'a dyn Role)],
-        votes: &'a HashMap<GamePlayer, GamePlayer>,
-    ) -> Vec<&'a dyn PlayerInterface> {
+        #[allow(unused)] winners: &'a [(&'a GamePlayer, &'a dyn Role)],
+        votes: &'a BTreeMap<GamePlayer, GamePlayer>,
+    ) -> Vec<&'a GamePlayer> {
         Vec::new()
     }
 }
 
 dyn_clone::clone_trait_object!(Role);
 
+/// The chain of roles `role` copied, starting with `role` itself. Each role in the chain copied
+/// the one after it.
+#[instrument(level = "trace")]
+pub fn copy_chain(role: &dyn Role) -> Vec<&dyn Role> {
+    let mut chain = vec![role];
+    let mut last = role;
+    while let Some(copied) = last.copied() {
+        chain.push(copied);
+        last = copied;
+    }
+    chain
+}
+
+/// The role a chain of copies resolves to: the last one in the chain, which copied nothing. A
+/// copier acts, votes and wins as this role.
+#[instrument(level = "trace")]
+pub fn resolve_copy(role: &dyn Role) -> &dyn Role {
+    copy_chain(role).pop().unwrap()
+}
+
 impl<'a> Display for &'a dyn Role {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.verbose_id())
@@ -0,0 +1,62 @@
+//! Role packs are JSON files describing roles for players: their names, teams, descriptions,
+//! images, wake order and the expansion they come from. The built-in roles ship as
+//! [`DEFAULT_PACK`], whose metadata fills in the role definitions in
+//! [`ROLES`](super::roles::ROLES). A pack named by [`PACK_VAR`] is laid over those at startup.
+
+use super::roles::{RoleDef, ROLES_STRINGS};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use thiserror::Error;
+
+pub static DEFAULT_PACK: Lazy<RolePack> =
+    Lazy::new(|| RolePack::from_json(include_str!("packs/default.json")).unwrap());
+
+/// The environment variable naming a role pack to load at startup.
+pub const PACK_VAR: &str = "ONUW_ROLE_PACK";
+
+#[derive(Error, Debug)]
+pub enum PackError {
+    #[error("Can't read the role pack: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Can't parse the role pack: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("The role pack describes {name}, but there is no such role.")]
+    UnknownRole { name: String },
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RolePack {
+    pub name: String,
+    pub roles: Vec<RoleDef>,
+}
+
+impl RolePack {
+    pub fn from_json(json: &str) -> Result<Self, PackError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Reads a pack from a file. Every role in it must be a built-in or scripted role.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PackError> {
+        Self::load_with(path, |name| ROLES_STRINGS.contains_key(name))
+    }
+
+    /// Reads a pack from a file, checking its roles with `is_role`.
+    pub(crate) fn load_with(
+        path: impl AsRef<Path>,
+        is_role: impl Fn(&str) -> bool,
+    ) -> Result<Self, PackError> {
+        let pack = Self::from_json(&std::fs::read_to_string(path)?)?;
+
+        match pack.roles.iter().find(|role| !is_role(&role.name)) {
+            Some(role) => Err(PackError::UnknownRole {
+                name: role.name.clone(),
+            }),
+            None => Ok(pack),
+        }
+    }
+
+    pub fn role(&self, name: &str) -> Option<&RoleDef> {
+        self.roles.iter().find(|role| role.name == name)
+    }
+}
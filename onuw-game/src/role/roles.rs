@@ -1,46 +1,152 @@
 mod common;
 
+pub mod alien;
+pub mod alphawolf;
+pub mod apprenticeassassin;
+pub mod apprenticeseer;
+pub mod apprenticetanner;
+pub mod assassin;
+pub mod auraseer;
+pub mod beholder;
+pub mod blob;
+pub mod bodyguard;
+pub mod bodysnatcher;
+pub mod copycat;
+pub mod count;
+pub mod cow;
+pub mod cupid;
+pub mod curator;
+pub mod cursed;
+pub mod diseased;
 pub mod doppelganger;
+pub mod dreamwolf;
 pub mod drunk;
+pub mod empath;
+pub mod exposer;
+pub mod gremlin;
+pub mod groob;
 pub mod hunter;
 pub mod insomniac;
+pub mod instigator;
+pub mod leader;
+pub mod marksman;
 pub mod mason;
+pub mod master;
 pub mod minion;
+pub mod mortician;
+pub mod mysticwolf;
+pub mod nostradamus;
+pub mod oracle;
+pub mod paranormalinvestigator;
+pub mod pickpocket;
+pub mod priest;
+pub mod prince;
+pub mod psychic;
+pub mod rascal;
+pub mod renfield;
+pub mod revealer;
 pub mod robber;
+pub mod scripted;
 pub mod seer;
+pub mod sentinel;
+pub mod squire;
+pub mod syntheticalien;
 pub mod tanner;
+pub mod thing;
 pub mod troublemaker;
+pub mod vampire;
+pub mod villageidiot;
 pub mod villager;
 pub mod werewolf;
+pub mod witch;
+pub mod zerb;
 
 use self::doppelganger::Doppelganger;
-use super::Role;
+use super::{
+    pack::{RolePack, DEFAULT_PACK, PACK_VAR},
+    script::{load_scripts, Script, ScriptError, SCRIPTS, SCRIPTS_DIR_VAR},
+    ActionPriority, Role,
+};
 use crate::role::roles::{
-    drunk::Drunk, hunter::Hunter, insomniac::Insomniac, mason::Mason, minion::Minion,
-    robber::Robber, seer::Seer, tanner::Tanner, troublemaker::Troublemaker, villager::Villager,
-    werewolf::Werewolf,
+    alien::Alien, alphawolf::AlphaWolf, apprenticeassassin::ApprenticeAssassin,
+    apprenticeseer::ApprenticeSeer, apprenticetanner::ApprenticeTanner, assassin::Assassin,
+    auraseer::AuraSeer, beholder::Beholder, blob::Blob, bodyguard::Bodyguard,
+    bodysnatcher::BodySnatcher, copycat::Copycat, count::Count, cow::Cow, cupid::Cupid,
+    curator::Curator, cursed::Cursed, diseased::Diseased, dreamwolf::DreamWolf, drunk::Drunk,
+    empath::Empath, exposer::Exposer, gremlin::Gremlin, groob::Groob, hunter::Hunter,
+    insomniac::Insomniac, instigator::Instigator, leader::Leader, marksman::Marksman, mason::Mason,
+    master::Master, minion::Minion, mortician::Mortician, mysticwolf::MysticWolf,
+    nostradamus::Nostradamus, oracle::Oracle, paranormalinvestigator::ParanormalInvestigator,
+    pickpocket::Pickpocket, priest::Priest, prince::Prince, psychic::Psychic, rascal::Rascal,
+    renfield::Renfield, revealer::Revealer, robber::Robber, seer::Seer, sentinel::Sentinel,
+    squire::Squire, syntheticalien::SyntheticAlien, tanner::Tanner, thing::Thing,
+    troublemaker::Troublemaker, vampire::Vampire, villageidiot::VillageIdiot, villager::Villager,
+    werewolf::Werewolf, witch::Witch, zerb::Zerb,
 };
 use cfg_if::cfg_if;
 use hashlink::LinkedHashMap;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use std::{any::type_name, collections::HashMap};
+use std::{any::type_name, collections::HashMap, sync::Arc};
+use tracing::warn;
 
-type RoleInitFn = fn() -> Box<dyn Role>;
+type RoleInitFn = Arc<dyn Fn() -> Box<dyn Role> + Send + Sync>;
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 pub struct RoleDef {
     pub name: String,
+    /// The name shown to players, e.g. `Alpha Wolf` for the `AlphaWolf`.
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(default)]
+    pub team: String,
+    #[serde(default)]
+    pub description: String,
+    /// The stem of the role's image file.
+    #[serde(default)]
+    pub image: String,
+    /// The priority the role first wakes up at, if it wakes up at all.
+    #[serde(default)]
+    pub wake_order: Option<ActionPriority>,
     pub min_amt: usize,
     pub max_amt: usize,
+    #[serde(default)]
+    pub expansion: String,
 }
 
 impl RoleDef {
+    /// The definition of `T` from the default role pack, or a bare one if the pack lacks it.
     fn new<T: Role + 'static>() -> Self {
-        Self {
-            name: type_name::<T>().split("::").last().unwrap().to_string(),
+        let name = type_name::<T>().split("::").last().unwrap().to_string();
+
+        DEFAULT_PACK.role(&name).cloned().unwrap_or_else(|| Self {
+            display_name: name.clone(),
+            name,
             min_amt: T::min_amt(),
             max_amt: T::max_amt(),
+            ..Default::default()
+        })
+    }
+
+    /// This role as `pack` describes it. The team and wake order follow from what the role does,
+    /// so they stay as they are, as does anything else the pack leaves empty.
+    fn with_pack_def(self, pack_def: &RoleDef) -> Self {
+        let or_own = |packed: &String, own: String| {
+            if packed.is_empty() {
+                own
+            } else {
+                packed.clone()
+            }
+        };
+
+        Self {
+            display_name: or_own(&pack_def.display_name, self.display_name),
+            description: or_own(&pack_def.description, self.description),
+            image: or_own(&pack_def.image, self.image),
+            min_amt: pack_def.min_amt,
+            max_amt: pack_def.max_amt,
+            expansion: or_own(&pack_def.expansion, self.expansion),
+            ..self
         }
     }
 
@@ -56,30 +162,171 @@ fn roledef_pair<T: Role + 'static>() -> (RoleDef, Option<RoleInitFn>) {
             let fun = None;
         }
         else{
-            let fun: Option<RoleInitFn> = Some(RoleDef::role_as_boxed::<T>);
+            let fun: Option<RoleInitFn> = Some(Arc::new(RoleDef::role_as_boxed::<T>));
         }
     };
 
     (RoleDef::new::<T>(), fun)
 }
 
+/// The role `script` describes, unless one of `roles` already has its name.
+pub(crate) fn scripted_pair(
+    script: Script,
+    roles: &LinkedHashMap<RoleDef, Option<RoleInitFn>>,
+) -> Result<(RoleDef, Option<RoleInitFn>), ScriptError> {
+    if roles.keys().any(|def| def.name == script.name) {
+        return Err(ScriptError::NameTaken { role: script.name });
+    }
+
+    let def = RoleDef {
+        name: script.name.clone(),
+        display_name: script.name.clone(),
+        team: script.team.clone(),
+        wake_order: script.priority.clone(),
+        min_amt: 1,
+        max_amt: script.max_amt,
+        // scripts have no card art of their own, so they show the card of their type
+        image: script.role_type.to_string().to_lowercase(),
+        expansion: "Scripted".to_string(),
+        ..Default::default()
+    };
+
+    cfg_if! {
+        if #[cfg(feature = "light")] {
+            let fun = None;
+        }
+        else{
+            let script = Arc::new(script);
+            let fun: Option<RoleInitFn> = Some(Arc::new(move || {
+                Box::new(scripted::ScriptedRole::from_script(script.clone())) as Box<dyn Role>
+            }));
+        }
+    };
+
+    Ok((def, fun))
+}
+
+/// The scripts in the directory named by [`SCRIPTS_DIR_VAR`], leaving out any that don't parse.
+fn runtime_scripts() -> Vec<Script> {
+    let Some(dir) = std::env::var_os(SCRIPTS_DIR_VAR) else {
+        return Vec::new();
+    };
+
+    match load_scripts(&dir) {
+        Ok(scripts) => scripts
+            .into_iter()
+            .filter_map(|(path, script)| {
+                script
+                    .map_err(|e| warn!("skipping {}: {}", path.display(), e))
+                    .ok()
+            })
+            .collect(),
+        Err(e) => {
+            warn!("could not read the scripts in {:?}: {}", dir, e);
+            Vec::new()
+        }
+    }
+}
+
+/// `roles` with the definitions `pack` gives them.
+pub(crate) fn apply_pack(
+    roles: LinkedHashMap<RoleDef, Option<RoleInitFn>>,
+    pack: &RolePack,
+) -> LinkedHashMap<RoleDef, Option<RoleInitFn>> {
+    roles
+        .into_iter()
+        .map(|(def, fun)| match pack.role(&def.name) {
+            Some(pack_def) => (def.with_pack_def(pack_def), fun),
+            None => (def, fun),
+        })
+        .collect()
+}
+
 pub static ROLES: Lazy<LinkedHashMap<RoleDef, Option<RoleInitFn>>> = Lazy::new(|| {
-    [
+    let mut roles: LinkedHashMap<_, _> = [
+        roledef_pair::<Alien>(),
+        roledef_pair::<AlphaWolf>(),
+        roledef_pair::<ApprenticeAssassin>(),
+        roledef_pair::<ApprenticeSeer>(),
+        roledef_pair::<ApprenticeTanner>(),
+        roledef_pair::<Assassin>(),
+        roledef_pair::<AuraSeer>(),
+        roledef_pair::<Beholder>(),
+        roledef_pair::<Blob>(),
+        roledef_pair::<Bodyguard>(),
+        roledef_pair::<BodySnatcher>(),
+        roledef_pair::<Copycat>(),
+        roledef_pair::<Count>(),
+        roledef_pair::<Cow>(),
+        roledef_pair::<Cupid>(),
+        roledef_pair::<Curator>(),
+        roledef_pair::<Cursed>(),
+        roledef_pair::<Diseased>(),
         roledef_pair::<Doppelganger>(),
+        roledef_pair::<DreamWolf>(),
         roledef_pair::<Drunk>(),
+        roledef_pair::<Empath>(),
+        roledef_pair::<Exposer>(),
+        roledef_pair::<Gremlin>(),
+        roledef_pair::<Groob>(),
         roledef_pair::<Hunter>(),
         roledef_pair::<Insomniac>(),
+        roledef_pair::<Instigator>(),
+        roledef_pair::<Leader>(),
+        roledef_pair::<Marksman>(),
         roledef_pair::<Mason>(),
+        roledef_pair::<Master>(),
         roledef_pair::<Minion>(),
+        roledef_pair::<Mortician>(),
+        roledef_pair::<MysticWolf>(),
+        roledef_pair::<Nostradamus>(),
+        roledef_pair::<Oracle>(),
+        roledef_pair::<ParanormalInvestigator>(),
+        roledef_pair::<Pickpocket>(),
+        roledef_pair::<Priest>(),
+        roledef_pair::<Prince>(),
+        roledef_pair::<Psychic>(),
+        roledef_pair::<Rascal>(),
+        roledef_pair::<Renfield>(),
+        roledef_pair::<Revealer>(),
         roledef_pair::<Robber>(),
         roledef_pair::<Seer>(),
+        roledef_pair::<Sentinel>(),
+        roledef_pair::<Squire>(),
+        roledef_pair::<SyntheticAlien>(),
         roledef_pair::<Tanner>(),
+        roledef_pair::<Thing>(),
         roledef_pair::<Troublemaker>(),
+        roledef_pair::<Vampire>(),
+        roledef_pair::<VillageIdiot>(),
         roledef_pair::<Villager>(),
         roledef_pair::<Werewolf>(),
+        roledef_pair::<Witch>(),
+        roledef_pair::<Zerb>(),
     ]
     .into_iter()
-    .collect()
+    .collect();
+
+    let bundled = SCRIPTS.iter().map(|source| Script::parse(source).unwrap());
+    for script in bundled.chain(runtime_scripts()) {
+        match scripted_pair(script, &roles) {
+            Ok((def, fun)) => {
+                roles.insert(def, fun);
+            }
+            Err(e) => warn!("skipping a script: {}", e),
+        }
+    }
+
+    let Some(path) = std::env::var_os(PACK_VAR) else {
+        return roles;
+    };
+    match RolePack::load_with(&path, |name| roles.keys().any(|def| def.name == name)) {
+        Ok(pack) => apply_pack(roles, &pack),
+        Err(e) => {
+            warn!("not using the role pack {:?}: {}", path, e);
+            roles
+        }
+    }
 });
 
 pub static ROLES_STRINGS: Lazy<HashMap<String, RoleDef>> = Lazy::new(|| {
@@ -0,0 +1,211 @@
+use super::common::{
+    choose_center_card, get_role_from_chosen_target_player, give_random_instruction, role_of,
+    show_type, swap_role_with_target_player,
+};
+use crate::{
+    game::{mark::Mark, GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::{collections::BTreeSet, fmt::Display, pin::Pin};
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Alien>> = Lazy::new(|| {
+    [("2D".to_string(), Alien::wake as ActionFn<Alien>)]
+        .into_iter()
+        .collect()
+});
+
+/// What the app tells the aliens to do. One instruction is drawn per night and every alien
+/// follows it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    /// Each alien looks at one center card.
+    ViewCenter,
+    /// Each alien looks at the card of a player who is not an alien.
+    ViewPlayer,
+    /// The aliens turn a player who is not an alien into one of them.
+    TurnAlien,
+    /// Each alien swaps their card with the card of a player who is not an alien.
+    SwapCards,
+}
+
+impl Instruction {
+    pub const ALL: [Instruction; 4] = [
+        Instruction::ViewCenter,
+        Instruction::ViewPlayer,
+        Instruction::TurnAlien,
+        Instruction::SwapCards,
+    ];
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Alien;
+
+#[async_trait]
+impl Role for Alien {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Alien".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Alien
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Self::eval_wincon(game, player, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Alien {
+    #[instrument(level = "trace")]
+    fn wake<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            Self::wake_aliens(self, game, player).await;
+        })
+    }
+
+    /// Shows `player` the other aliens and has them follow tonight's instruction.
+    #[instrument(level = "trace")]
+    pub(crate) async fn wake_aliens(
+        calling_role: &dyn Role,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) {
+        show_type(calling_role, game, player, &RoleType::Alien).await;
+
+        let instruction = match Self::drawn_instruction(game) {
+            Some(instruction) => {
+                game.show_instruction(player, &instruction.to_string())
+                    .await
+                    .unwrap();
+                instruction
+            }
+            None => give_random_instruction(game, player, &Alien::new().id(), &Instruction::ALL)
+                .await
+                .unwrap(),
+        };
+
+        let aliens = game
+            .players_by_type()
+            .get(&RoleType::Alien)
+            .cloned()
+            .unwrap_or_else(BTreeSet::new);
+        let others: Vec<_> = game
+            .all_other_players(player)
+            .into_iter()
+            .filter(|p| !aliens.contains(*p))
+            .cloned()
+            .collect();
+        let others: Vec<_> = others.iter().collect();
+
+        match instruction {
+            Instruction::ViewCenter => {
+                if let Some(i) = choose_center_card(game, player, &[]).await {
+                    game.show_role(
+                        player,
+                        RoleTarget::Center(i),
+                        role_of(calling_role, &game.centerroles()[i]).as_ref(),
+                    )
+                    .await
+                    .unwrap();
+                }
+            }
+            Instruction::ViewPlayer => {
+                if let Some((target, target_role)) =
+                    get_role_from_chosen_target_player(game, player, &others).await
+                {
+                    game.show_role(
+                        player,
+                        RoleTarget::Player(target),
+                        role_of(calling_role, target_role).as_ref(),
+                    )
+                    .await
+                    .unwrap();
+                }
+            }
+            Instruction::TurnAlien => {
+                // the first alien to wake makes the choice for everyone
+                if others.is_empty() || game.marks().values().any(|m| *m == Mark::Alien) {
+                    return;
+                }
+
+                let target = player.choose_player(&others).await.unwrap();
+                game.place_mark(&target, Mark::Alien);
+            }
+            Instruction::SwapCards => {
+                swap_role_with_target_player(calling_role, game, player, &others).await;
+            }
+        }
+    }
+
+    /// Tonight's instruction, if an alien already woke up and drew it.
+    #[instrument(level = "trace")]
+    fn drawn_instruction(game: &ONUWGame) -> Option<Instruction> {
+        game.random_draws_for(&Alien::new().id())
+            .last()
+            .and_then(|d| Instruction::ALL.into_iter().find(|i| i.to_string() == d))
+    }
+
+    /// The aliens win if none of them dies.
+    #[instrument(level = "trace", skip(dead))]
+    pub fn eval_wincon(
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        dead.iter()
+            .all(|(_, r)| r.try_read().unwrap().team() != RoleType::Alien.to_string())
+    }
+}
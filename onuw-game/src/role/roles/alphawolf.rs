@@ -0,0 +1,139 @@
+use super::{
+    common::{get_role_from_chosen_target_player, role_type_of},
+    werewolf::Werewolf,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::{collections::BTreeSet, pin::Pin};
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<AlphaWolf>> = Lazy::new(|| {
+    [
+        ("2".to_string(), AlphaWolf::wake as ActionFn<AlphaWolf>),
+        (
+            "2B".to_string(),
+            AlphaWolf::night_action as ActionFn<AlphaWolf>,
+        ),
+    ]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct AlphaWolf;
+
+#[async_trait]
+impl Role for AlphaWolf {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "AlphaWolf".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Werewolf
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Werewolf::eval_wincon(game, player, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl AlphaWolf {
+    #[instrument(level = "trace")]
+    fn wake<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            Werewolf::wake(self, game, player).await;
+        })
+    }
+
+    /// Swaps the center werewolf card with the card of a player who is not a werewolf.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let Some(werewolf_card) = game.werewolf_card().cloned() else {
+                return;
+            };
+
+            let (target, target_role);
+
+            {
+                let werewolves = game
+                    .players_by_type()
+                    .get(&RoleType::Werewolf)
+                    .cloned()
+                    .unwrap_or_else(BTreeSet::new);
+
+                let choices: Vec<_> = game
+                    .all_other_players(player)
+                    .into_iter()
+                    .filter(|p| !werewolves.contains(*p))
+                    .collect();
+
+                let Some((one, two)) =
+                    get_role_from_chosen_target_player(game, player, &choices).await
+                else {
+                    return;
+                };
+                (target, target_role) = (one, two.clone());
+            }
+
+            let old_type = role_type_of(self, &target_role);
+
+            game.change_role(&RoleTarget::Player(target.clone()), &werewolf_card)
+                .await;
+            game.update_player_type(&target, &old_type, RoleType::Werewolf);
+            game.set_werewolf_card(target_role);
+        })
+    }
+}
@@ -0,0 +1,115 @@
+use super::{assassin::Assassin, common::show_type};
+use crate::{
+    game::{mark::Mark, GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+const ASSASSIN: RoleType = RoleType::Other("Assassin");
+
+static ACTIONS: Lazy<ActionFnMap<ApprenticeAssassin>> = Lazy::new(|| {
+    [(
+        "1J".to_string(),
+        ApprenticeAssassin::night_action as ActionFn<ApprenticeAssassin>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct ApprenticeAssassin;
+
+#[async_trait]
+impl Role for ApprenticeAssassin {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "ApprenticeAssassin".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Other("ApprenticeAssassin")
+    }
+
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        self.id()
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        if game
+            .players_by_type()
+            .get(&ASSASSIN)
+            .is_some_and(|assassins| !assassins.is_empty())
+        {
+            dead.iter()
+                .any(|(_, r)| r.try_read().unwrap().role_type() == ASSASSIN)
+        } else {
+            Assassin::eval_wincon(game, player, dead)
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl ApprenticeAssassin {
+    /// Shows the Apprentice Assassin the Assassin, or lets them mark a player themselves if
+    /// there is none.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            if show_type(self, game, player, &ASSASSIN).await > 0 {
+                return;
+            }
+
+            // without an Assassin the apprentice does the job themselves
+            let target = {
+                let choices = game.all_other_players(player);
+                if choices.is_empty() {
+                    return;
+                }
+
+                player.choose_player(&choices).await.unwrap()
+            };
+
+            game.place_mark(&target, Mark::Assassin);
+        })
+    }
+}
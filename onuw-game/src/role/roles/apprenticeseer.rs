@@ -0,0 +1,98 @@
+use super::{
+    common::{choose_center_card, role_of},
+    villager::Villager,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<ApprenticeSeer>> = Lazy::new(|| {
+    [(
+        "5B".to_string(),
+        ApprenticeSeer::night_action as ActionFn<ApprenticeSeer>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct ApprenticeSeer;
+
+#[async_trait]
+impl Role for ApprenticeSeer {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "ApprenticeSeer".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl ApprenticeSeer {
+    /// Looks at one center card.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let Some(i) = choose_center_card(game, player, &[]).await else {
+                return;
+            };
+
+            game.show_role(
+                player,
+                RoleTarget::Center(i),
+                role_of(self, &game.centerroles()[i]).as_ref(),
+            )
+            .await
+            .unwrap();
+        })
+    }
+}
@@ -0,0 +1,101 @@
+use super::{common::show_role, tanner::Tanner};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<ApprenticeTanner>> = Lazy::new(|| {
+    [(
+        "3B".to_string(),
+        ApprenticeTanner::night_action as ActionFn<ApprenticeTanner>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct ApprenticeTanner;
+
+#[async_trait]
+impl Role for ApprenticeTanner {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "ApprenticeTanner".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        Tanner::new().id()
+    }
+
+    /// Wins if the Tanner dies, or, if no player is the Tanner, if they die themselves.
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        let tanner = Tanner::new().id();
+
+        if game
+            .players()
+            .values()
+            .any(|r| r.try_read().is_ok_and(|r| r.effective_id() == tanner))
+        {
+            dead.iter()
+                .any(|(_, r)| r.try_read().unwrap().effective_id() == tanner)
+        } else {
+            Tanner::eval_wincon(game, player, dead)
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl ApprenticeTanner {
+    /// Learns who the Tanner is.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            show_role(game, player, &Tanner::new()).await;
+        })
+    }
+}
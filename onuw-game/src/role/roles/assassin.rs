@@ -0,0 +1,131 @@
+use crate::{
+    game::{mark::Mark, GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::{collections::BTreeMap, pin::Pin};
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Assassin>> = Lazy::new(|| {
+    [(
+        "1I".to_string(),
+        Assassin::night_action as ActionFn<Assassin>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Assassin;
+
+#[async_trait]
+impl Role for Assassin {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Assassin".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Other("Assassin")
+    }
+
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        self.id()
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Self::eval_wincon(game, player, dead)
+    }
+
+    /// The player the Assassin marked cannot win if the Assassin does.
+    #[instrument(level = "trace", skip(winners))]
+    fn prevent_win<'a>(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        winners: &'a [(&'a GamePlayer, &'a dyn Role)],
+        votes: &'a BTreeMap<GamePlayer, GamePlayer>,
+    ) -> Vec<&'a GamePlayer> {
+        if winners.iter().any(|(p, _)| *p == player) {
+            winners
+                .iter()
+                .filter(|(p, _)| {
+                    game.marks()
+                        .iter()
+                        .any(|(m, mark)| *mark == Mark::Assassin && m == *p)
+                })
+                .map(|(p, _)| p.to_owned())
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Assassin {
+    /// Whether a player holding the Mark of the Assassin died.
+    #[instrument(level = "trace", skip(dead))]
+    pub fn eval_wincon(
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        dead.iter().any(|(p, _)| game.mark_of(p) == Mark::Assassin)
+    }
+
+    /// Places the Mark of the Assassin on another player.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let target = {
+                let choices = game.all_other_players(player);
+                if choices.is_empty() {
+                    return;
+                }
+
+                player.choose_player(&choices).await.unwrap()
+            };
+
+            game.place_mark(&target, Mark::Assassin);
+        })
+    }
+}
@@ -0,0 +1,101 @@
+use super::villager::Villager;
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<AuraSeer>> = Lazy::new(|| {
+    [(
+        "7C".to_string(),
+        AuraSeer::night_action as ActionFn<AuraSeer>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct AuraSeer;
+
+#[async_trait]
+impl Role for AuraSeer {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "AuraSeer".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl AuraSeer {
+    /// Learns which other players have moved or viewed a card so far tonight.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let events = game.night_events();
+            let acted: Vec<_> = game
+                .seats()
+                .iter()
+                .filter(|p| *p != player && events.iter().any(|e| e.player() == p.id()))
+                .collect();
+
+            for target in acted {
+                game.show_acted(player, RoleTarget::Player(target.clone()))
+                    .await
+                    .unwrap();
+            }
+        })
+    }
+}
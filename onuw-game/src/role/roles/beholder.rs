@@ -0,0 +1,118 @@
+use super::{
+    common::{role_of, show_role, unshielded},
+    seer::Seer,
+    villager::Villager,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Beholder>> = Lazy::new(|| {
+    [(
+        "5D".to_string(),
+        Beholder::night_action as ActionFn<Beholder>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Beholder;
+
+#[async_trait]
+impl Role for Beholder {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Beholder".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Beholder {
+    /// Sees who the Seer is and may then look at their card.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let seer = Seer::new().id();
+            let seers: Vec<_> = game
+                .all_other_players(player)
+                .into_iter()
+                .filter(|p| {
+                    game.players()[*p]
+                        .try_read()
+                        .is_ok_and(|r| r.effective_id() == seer)
+                })
+                .collect();
+
+            if seers.is_empty() {
+                return;
+            }
+
+            show_role(game, player, &Seer::new()).await;
+
+            if !player.choose_bool().await.unwrap() {
+                return;
+            }
+
+            for target in unshielded(game, &seers) {
+                game.show_role(
+                    player,
+                    RoleTarget::Player(target.clone()),
+                    role_of(self, &game.players()[target]).as_ref(),
+                )
+                .await
+                .unwrap();
+            }
+        })
+    }
+}
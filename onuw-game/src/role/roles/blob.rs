@@ -0,0 +1,156 @@
+use super::common::give_random_instruction;
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use std::{fmt::Display, pin::Pin};
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Blob>> = Lazy::new(|| {
+    [("2I".to_string(), Blob::night_action as ActionFn<Blob>)]
+        .into_iter()
+        .collect()
+});
+
+/// Which of the Blob's neighbours the app randomly makes part of the blob.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shape {
+    Left,
+    Right,
+    BothSides,
+    TwoLeft,
+    TwoRight,
+}
+
+impl Shape {
+    pub const ALL: [Shape; 5] = [
+        Shape::Left,
+        Shape::Right,
+        Shape::BothSides,
+        Shape::TwoLeft,
+        Shape::TwoRight,
+    ];
+
+    /// The seat offsets, relative to the Blob, that are part of the blob.
+    fn offsets(&self) -> &'static [isize] {
+        match self {
+            Shape::Left => &[-1],
+            Shape::Right => &[1],
+            Shape::BothSides => &[-1, 1],
+            Shape::TwoLeft => &[-1, -2],
+            Shape::TwoRight => &[1, 2],
+        }
+    }
+}
+
+impl Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Blob {
+    shape: Option<Shape>,
+}
+
+#[async_trait]
+impl Role for Blob {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self { shape: None }
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Blob".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Other("Blob")
+    }
+
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        self.id()
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        let mut blob = self.members(game, player);
+        blob.push(player);
+
+        dead.iter().all(|(v, _)| !blob.contains(&v))
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Blob {
+    /// Learns which neighbours make up the blob. Each of them is told as well.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let shape = give_random_instruction(game, player, &self.id(), &Shape::ALL)
+                .await
+                .unwrap();
+            self.shape = Some(shape);
+
+            let members: Vec<_> = self.members(game, player).into_iter().cloned().collect();
+            for member in members.iter() {
+                game.show_instruction(member, &shape.to_string())
+                    .await
+                    .unwrap();
+            }
+        })
+    }
+
+    /// The other players in the blob of whoever holds the Blob card. Nobody is part of the blob
+    /// until the Blob wakes up.
+    #[instrument(level = "trace")]
+    fn members<'a>(&self, game: &'a ONUWGame, player: &GamePlayer) -> Vec<&'a GamePlayer> {
+        let Some(shape) = self.shape else {
+            return Vec::new();
+        };
+
+        shape
+            .offsets()
+            .iter()
+            .filter_map(|offset| game.seat_at_offset(player, *offset))
+            .filter(|p| *p != player)
+            .unique()
+            .collect()
+    }
+}
@@ -0,0 +1,48 @@
+use super::villager::Villager;
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, Role},
+};
+use std::collections::BTreeMap;
+use tracing::instrument;
+
+/// Protects whoever they vote for from dying.
+#[derive(Clone, Debug)]
+pub struct Bodyguard;
+
+impl Role for Bodyguard {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Bodyguard".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace")]
+    fn protect_from_vote(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        votes: &BTreeMap<GamePlayer, GamePlayer>,
+    ) -> Vec<GamePlayer> {
+        votes.get(player).cloned().into_iter().collect()
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+}
@@ -0,0 +1,102 @@
+use super::{alien::Alien, common::swap_role_with_target_player};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::{collections::BTreeSet, pin::Pin};
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<BodySnatcher>> = Lazy::new(|| {
+    [(
+        "2L".to_string(),
+        BodySnatcher::night_action as ActionFn<BodySnatcher>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct BodySnatcher;
+
+#[async_trait]
+impl Role for BodySnatcher {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "BodySnatcher".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Alien
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Alien::eval_wincon(game, player, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl BodySnatcher {
+    /// May swap cards with a player who is not an alien. That player now holds the Body Snatcher
+    /// and becomes an alien, while the Body Snatcher takes on their old role.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            if !player.choose_bool().await.unwrap() {
+                return;
+            }
+
+            let aliens = game
+                .players_by_type()
+                .get(&RoleType::Alien)
+                .cloned()
+                .unwrap_or_else(BTreeSet::new);
+            let others: Vec<_> = game
+                .all_other_players(player)
+                .into_iter()
+                .filter(|p| !aliens.contains(*p))
+                .cloned()
+                .collect();
+            let others: Vec<_> = others.iter().collect();
+
+            swap_role_with_target_player(self, game, player, &others).await;
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::{collections::BTreeSet, fmt::Display};
 
 use crate::{
     game::{GamePlayer, GameRole, ONUWGame},
@@ -23,14 +23,13 @@ pub async fn show_type(
     stream::iter(
         game.players_by_type()
             .get(role_type)
-            .unwrap_or(&HashSet::new())
+            .unwrap_or(&BTreeSet::new())
             .iter()
-            .filter(|v| v.as_ref() != player.as_ref()),
+            .filter(|v| *v != player),
     )
     .inspect(|_| count += 1)
     .for_each_concurrent(None, |v| async {
-        player
-            .show_role_type(RoleTarget::Player(v.clone()), role_type)
+        game.show_role_type(player, RoleTarget::Player(v.clone()), role_type)
             .await
             .unwrap();
     })
@@ -44,9 +43,8 @@ pub async fn show_role(game: &ONUWGame, player: &GamePlayer, role: &dyn Role) {
     stream::iter(game.players())
         .for_each(|(p, r)| async {
             if let Ok(r) = r.try_read() {
-                if r.as_ref() == role && p.as_ref() != player.as_ref() {
-                    player
-                        .show_role(RoleTarget::Player(p.clone()), role)
+                if r.as_ref() == role && *p != *player {
+                    game.show_role_holder(player, RoleTarget::Player(p.clone()), role)
                         .await
                         .unwrap();
                 }
@@ -55,6 +53,8 @@ pub async fn show_role(game: &ONUWGame, player: &GamePlayer, role: &dyn Role) {
         .await;
 }
 
+/// Swaps `player`'s card with the card of a player chosen by `asker`. Nothing happens if
+/// either card is shielded.
 #[instrument(level = "trace")]
 pub async fn swap_role_with_target_player_with_asker<'a>(
     calling_role: &dyn Role,
@@ -62,12 +62,16 @@ pub async fn swap_role_with_target_player_with_asker<'a>(
     player: &'a GamePlayer,
     asker: &'a GamePlayer,
     choices: &'a [&'a GamePlayer],
-) -> (GameRole, GameRole) {
+) -> Option<(GameRole, GameRole)> {
+    if game.is_shielded(player) {
+        return None;
+    }
+
     let (target, targetrole);
     let orig_role;
 
     {
-        let (one, two) = get_role_from_chosen_target_player(game, asker, choices).await;
+        let (one, two) = get_role_from_chosen_target_player(game, asker, choices).await?;
         (target, targetrole) = (one, two.clone());
         orig_role = game.players().get(player).unwrap().clone();
     }
@@ -75,22 +79,17 @@ pub async fn swap_role_with_target_player_with_asker<'a>(
     game.change_role(&RoleTarget::Player(player.clone()), &targetrole)
         .await;
 
-    game.update_player_type(
-        player,
-        &calling_role.role_type(),
-        targetrole.try_read().unwrap().role_type(),
-    );
+    let target_type = role_type_of(calling_role, &targetrole);
+    let orig_type = role_type_of(calling_role, &orig_role);
+
+    game.update_player_type(player, &orig_type, target_type.clone());
 
     game.change_role(&RoleTarget::Player(target.clone()), &orig_role)
         .await;
 
-    game.update_player_type(
-        &target,
-        &targetrole.try_read().unwrap().role_type(),
-        calling_role.role_type(),
-    );
+    game.update_player_type(&target, &target_type, orig_type);
 
-    (targetrole, orig_role)
+    Some((targetrole, orig_role))
 }
 
 #[instrument(level = "trace")]
@@ -99,23 +98,111 @@ pub async fn swap_role_with_target_player<'a>(
     game: &'a mut ONUWGame,
     player: &'a GamePlayer,
     choices: &'a [&'a GamePlayer],
-) -> (GameRole, GameRole) {
+) -> Option<(GameRole, GameRole)> {
     return swap_role_with_target_player_with_asker(calling_role, game, player, player, choices)
         .await;
 }
 
+#[instrument(level = "trace")]
+pub async fn choose_center_card(
+    game: &ONUWGame,
+    player: &GamePlayer,
+    exclude: &[usize],
+) -> Option<usize> {
+    let choices: Vec<isize> = (0..game.centerroles().len())
+        .filter(|i| !exclude.contains(i))
+        .map(|i| i.try_into().unwrap())
+        .collect();
+
+    if choices.is_empty() {
+        None
+    } else {
+        Some(
+            player
+                .choose_num(&choices)
+                .await
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        )
+    }
+}
+
+/// Lets `player` choose one of `choices` whose card is not shielded, or returns `None` if
+/// there is no such player.
 #[instrument(level = "trace")]
 pub async fn get_role_from_chosen_target_player<'a>(
     game: &'a ONUWGame,
     player: &GamePlayer,
     choices: &'a [&'a GamePlayer],
-) -> (GamePlayer, &'a GameRole) {
-    let target = player.choose_player(choices).await.unwrap();
+) -> Option<(GamePlayer, &'a GameRole)> {
+    let choices = unshielded(game, choices);
+    if choices.is_empty() {
+        return None;
+    }
+
+    let target = player.choose_player(&choices).await.unwrap();
 
     let targetrole = game
         .players()
         .get(&target)
         .unwrap_or_else(|| panic!("Why does player {} not have a role?", target));
 
-    (target, targetrole)
+    Some((target, targetrole))
+}
+
+/// Draws one of `instructions` at random, records it under `purpose` and tells `player`
+/// what they were instructed to do.
+#[instrument(level = "trace", skip(instructions))]
+pub async fn give_random_instruction<T: Clone + Display>(
+    game: &mut ONUWGame,
+    player: &GamePlayer,
+    purpose: &str,
+    instructions: &[T],
+) -> Option<T> {
+    let instruction = game.draw_random(purpose, instructions)?;
+    game.show_instruction(player, &instruction.to_string())
+        .await
+        .unwrap();
+
+    Some(instruction)
+}
+
+/// Swaps the marks in front of `one` and `two`. Unlike cards, marks cannot be shielded.
+#[instrument(level = "trace")]
+pub fn swap_marks(game: &mut ONUWGame, one: &GamePlayer, two: &GamePlayer) {
+    let (mark_one, mark_two) = (game.mark_of(one), game.mark_of(two));
+    game.place_mark(one, mark_two);
+    game.place_mark(two, mark_one);
+}
+
+/// The type of `role`. The only card locked during the night is the one whose action is being
+/// performed, so a card that cannot be read is the card of `calling_role`, e.g. when the Robber
+/// robs back their card from the Doppelganger.
+#[instrument(level = "trace")]
+pub fn role_type_of(calling_role: &dyn Role, role: &GameRole) -> RoleType {
+    match role.try_read() {
+        Ok(role) => role.role_type(),
+        Err(_) => calling_role.role_type(),
+    }
+}
+
+/// A copy of `role`, falling back to `calling_role` for the locked card like [`role_type_of`],
+/// e.g. when another player's card is viewed after it was swapped with the acting card.
+#[instrument(level = "trace")]
+pub fn role_of<'a>(calling_role: &(dyn Role + 'a), role: &GameRole) -> Box<dyn Role + 'a> {
+    match role.try_read() {
+        Ok(role) => role.clone(),
+        Err(_) => dyn_clone::clone_box(calling_role),
+    }
+}
+
+/// Drops the players whose cards are shielded by the Sentinel.
+#[instrument(level = "trace")]
+pub fn unshielded<'a>(game: &ONUWGame, choices: &[&'a GamePlayer]) -> Vec<&'a GamePlayer> {
+    choices
+        .iter()
+        .filter(|p| !game.is_shielded(p))
+        .copied()
+        .collect()
 }
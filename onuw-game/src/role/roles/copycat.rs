@@ -0,0 +1,222 @@
+use super::{common::choose_center_card, villager::Villager};
+use crate::{
+    game::{voteaction::ONUWGameVoteAction, GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{
+        copy_chain, resolve_copy, roletype::RoleType, ActionFn, ActionFnMap, ActionPriority,
+        CopyBehaviour, Role, MAX_COPY_DEPTH,
+    },
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::{collections::BTreeMap, pin::Pin, vec::Vec};
+use tracing::{instrument, warn};
+
+const PRIORITY: &str = "0B";
+
+static ACTIONS: Lazy<ActionFnMap<Copycat>> = Lazy::new(|| {
+    [(
+        PRIORITY.to_string(),
+        Copycat::night_action as ActionFn<Copycat>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Copycat {
+    copied: Option<Box<dyn Role>>,
+}
+
+#[async_trait]
+impl Role for Copycat {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self { copied: None }
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Copycat".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn verbose_id(&self) -> String {
+        format!(
+            "{}({})",
+            self.id(),
+            self.copied
+                .as_ref()
+                .map(|r| r.verbose_id())
+                .unwrap_or_default()
+        )
+    }
+
+    #[instrument(level = "trace")]
+    fn effective_id(&self) -> String {
+        resolve_copy(self).id()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        if let Some(role) = &self.copied {
+            role.role_type()
+        } else {
+            RoleType::Villager
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        if let Some(role) = &self.copied {
+            role.team()
+        } else {
+            RoleType::Villager.to_string()
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn after_vote(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        votes: &BTreeMap<GamePlayer, GamePlayer>,
+        dead: &[GamePlayer],
+    ) -> Vec<ONUWGameVoteAction> {
+        if let Some(role) = &self.copied {
+            role.after_vote(game, player, votes, dead)
+        } else {
+            Vec::new()
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn protect_from_vote(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        votes: &BTreeMap<GamePlayer, GamePlayer>,
+    ) -> Vec<GamePlayer> {
+        if let Some(role) = &self.copied {
+            role.protect_from_vote(game, player, votes)
+        } else {
+            Vec::new()
+        }
+    }
+
+    #[instrument(level = "trace", skip(winners))]
+    fn prevent_win<'a>(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        winners: &'a [(&'a GamePlayer, &'a dyn Role)],
+        votes: &'a BTreeMap<GamePlayer, GamePlayer>,
+    ) -> Vec<&'a GamePlayer> {
+        if let Some(role) = &self.copied {
+            role.prevent_win(game, player, winners, votes)
+        } else {
+            Vec::new()
+        }
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        if let Some(role) = &self.copied {
+            role.win_condition(game, player, dead)
+        } else {
+            Villager::eval_wincon(game, dead)
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copied(&self) -> Option<&dyn Role> {
+        self.copied.as_deref()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::Passive
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else if let Some(role) = self.copied.as_mut() {
+            role.action_at_priority(priority, game, player).await
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Copycat {
+    /// Looks at a center card and becomes that role. Unlike the Doppelganger, the Copycat
+    /// wakes before everyone else, so it simply acts again whenever the copied role does.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let Some(i) = choose_center_card(game, player, &[]).await else {
+                return;
+            };
+
+            let center_role = game.centerroles()[i].clone();
+            let copied = center_role.read().await.clone();
+
+            game.show_role(player, RoleTarget::Center(i), copied.as_ref())
+                .await
+                .unwrap();
+            if copy_chain(copied.as_ref()).len() >= MAX_COPY_DEPTH {
+                warn!(
+                    "{} is copied too many times over to copy again",
+                    copied.verbose_id()
+                );
+                return;
+            }
+            game.update_player_type(player, &RoleType::Villager, copied.role_type());
+
+            let pris: Vec<ActionPriority> = resolve_copy(copied.as_ref())
+                .priorities()
+                .into_iter()
+                .cloned()
+                .collect();
+            self.copied = Some(copied);
+
+            let game_role = game.players().get(player).unwrap().clone();
+            for pri in pris.iter() {
+                if pri.as_str() <= PRIORITY {
+                    // the copied role's turn is already over
+                    self.copied
+                        .as_mut()
+                        .unwrap()
+                        .action_at_priority(pri, game, player)
+                        .await
+                        .unwrap();
+                } else {
+                    game.add_night_action_at_priority(pri, &game_role, Some(player));
+                }
+            }
+        })
+    }
+}
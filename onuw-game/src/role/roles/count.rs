@@ -0,0 +1,104 @@
+use super::vampire::{choose_non_vampire, Vampire};
+use crate::{
+    game::{mark::Mark, GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Count>> = Lazy::new(|| {
+    [
+        ("1B".to_string(), Count::wake as ActionFn<Count>),
+        ("1C".to_string(), Count::night_action as ActionFn<Count>),
+    ]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Count;
+
+#[async_trait]
+impl Role for Count {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Count".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Vampire
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Vampire::eval_wincon(game, player, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Count {
+    #[instrument(level = "trace")]
+    fn wake<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            Vampire::wake_vampires(self, game, player).await;
+        })
+    }
+
+    /// Places the Mark of Fear on a player who is not a vampire.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let Some(target) = choose_non_vampire(game, player).await else {
+                return;
+            };
+
+            game.place_mark(&target, Mark::Fear);
+        })
+    }
+}
@@ -0,0 +1,102 @@
+use super::villager::Villager;
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Cow>> = Lazy::new(|| {
+    [("2E".to_string(), Cow::night_action as ActionFn<Cow>)]
+        .into_iter()
+        .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Cow;
+
+#[async_trait]
+impl Role for Cow {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Cow".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Cow {
+    /// Learns which of the players sitting next to the Cow are aliens.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let aliens: Vec<_> = game
+                .neighbours(player)
+                .into_iter()
+                .filter(|p| {
+                    game.players_by_type()
+                        .get(&RoleType::Alien)
+                        .is_some_and(|aliens| aliens.contains(*p))
+                })
+                .cloned()
+                .collect();
+
+            for alien in aliens {
+                game.show_role_type(player, RoleTarget::Player(alien), &RoleType::Alien)
+                    .await
+                    .unwrap();
+            }
+        })
+    }
+}
@@ -0,0 +1,95 @@
+use super::villager::Villager;
+use crate::{
+    game::{mark::Mark, GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Cupid>> = Lazy::new(|| {
+    [("1F".to_string(), Cupid::night_action as ActionFn<Cupid>)]
+        .into_iter()
+        .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Cupid;
+
+#[async_trait]
+impl Role for Cupid {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Cupid".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Cupid {
+    /// Places the Mark of Love on two players, who may include the Cupid.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let lovers = {
+                let mut choices: Vec<_> = game.players().keys().collect();
+                if choices.len() < 2 {
+                    return;
+                }
+
+                let first = player.choose_player(&choices).await.unwrap();
+                choices.retain(|p| **p != first);
+                let second = player.choose_player(&choices).await.unwrap();
+                [first, second]
+            };
+
+            for lover in lovers.iter() {
+                game.place_mark(lover, Mark::Love);
+            }
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use super::villager::Villager;
+use crate::game::artifact::Artifact;
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Curator>> = Lazy::new(|| {
+    [("9C".to_string(), Curator::night_action as ActionFn<Curator>)]
+        .into_iter()
+        .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Curator;
+
+#[async_trait]
+impl Role for Curator {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Curator".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Curator {
+    /// Places a random unused artifact on any player's card that does not have one yet.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let target = {
+                let choices: Vec<_> = game
+                    .players()
+                    .keys()
+                    .filter(|p| !game.artifacts().contains_key(*p) && !game.is_shielded(p))
+                    .sorted()
+                    .collect();
+
+                if choices.is_empty() || !player.choose_bool().await.unwrap() {
+                    return;
+                }
+
+                player.choose_player(&choices).await.unwrap()
+            };
+
+            let unused: Vec<_> = Artifact::ALL
+                .iter()
+                .filter(|a| !game.artifacts().values().any(|v| v == *a))
+                .copied()
+                .collect();
+            let artifact = game.draw_random(&self.id(), &unused).unwrap();
+
+            game.place_artifact(self, &target, artifact);
+        })
+    }
+}
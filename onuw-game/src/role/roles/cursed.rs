@@ -0,0 +1,59 @@
+use super::{villager::Villager, werewolf::Werewolf};
+use crate::{
+    game::{voteaction::ONUWGameVoteAction, GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, Role},
+};
+use std::collections::BTreeMap;
+use tracing::instrument;
+
+#[derive(Clone, Debug)]
+pub struct Cursed;
+
+impl Role for Cursed {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Cursed".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    /// The Cursed becomes a werewolf as soon as anyone else votes for them.
+    #[instrument(level = "trace")]
+    fn after_vote(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        votes: &BTreeMap<GamePlayer, GamePlayer>,
+        dead: &[GamePlayer],
+    ) -> Vec<ONUWGameVoteAction> {
+        if votes
+            .iter()
+            .any(|(voter, target)| target == player && voter != player)
+        {
+            vec![ONUWGameVoteAction::Turn(
+                player.clone(),
+                Box::new(Werewolf::new()),
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+}
@@ -1,13 +1,17 @@
 use super::{common::get_role_from_chosen_target_player, villager::Villager};
 use crate::{
-    game::{GamePlayer, GameRole, ONUWGame},
+    game::{voteaction::ONUWGameVoteAction, GamePlayer, GameRole, ONUWGame},
     playerinterface::roletarget::RoleTarget,
-    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+    role::{
+        copy_chain, resolve_copy, roletype::RoleType, ActionFn, ActionFnMap, ActionPriority,
+        CopyBehaviour, Role, MAX_COPY_DEPTH,
+    },
 };
 use async_trait::async_trait;
 use futures::Future;
+use itertools::Itertools;
 use once_cell::sync::Lazy;
-use std::{fmt::Display, pin::Pin, vec::Vec};
+use std::{collections::BTreeMap, fmt::Display, pin::Pin, vec::Vec};
 use tracing::{instrument, warn};
 
 static ACTIONS: Lazy<ActionFnMap<Doppelganger>> = Lazy::new(|| {
@@ -50,11 +54,7 @@ impl Role for Doppelganger {
 
     #[instrument(level = "trace")]
     fn effective_id(&self) -> String {
-        if self.copied.is_some() {
-            self.copied.as_ref().unwrap().id()
-        } else {
-            self.id()
-        }
+        resolve_copy(self).id()
     }
 
     #[instrument(level = "trace")]
@@ -66,6 +66,59 @@ impl Role for Doppelganger {
         }
     }
 
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        if let Some(role) = &self.copied {
+            role.team()
+        } else {
+            RoleType::Villager.to_string()
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn after_vote(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        votes: &BTreeMap<GamePlayer, GamePlayer>,
+        dead: &[GamePlayer],
+    ) -> Vec<ONUWGameVoteAction> {
+        if let Some(role) = &self.copied {
+            role.after_vote(game, player, votes, dead)
+        } else {
+            Vec::new()
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn protect_from_vote(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        votes: &BTreeMap<GamePlayer, GamePlayer>,
+    ) -> Vec<GamePlayer> {
+        if let Some(role) = &self.copied {
+            role.protect_from_vote(game, player, votes)
+        } else {
+            Vec::new()
+        }
+    }
+
+    #[instrument(level = "trace", skip(winners))]
+    fn prevent_win<'a>(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        winners: &'a [(&'a GamePlayer, &'a dyn Role)],
+        votes: &'a BTreeMap<GamePlayer, GamePlayer>,
+    ) -> Vec<&'a GamePlayer> {
+        if let Some(role) = &self.copied {
+            role.prevent_win(game, player, winners, votes)
+        } else {
+            Vec::new()
+        }
+    }
+
     #[instrument(level = "trace", skip(dead))]
     fn win_condition(
         &self,
@@ -85,6 +138,16 @@ impl Role for Doppelganger {
         ACTIONS.keys().collect()
     }
 
+    #[instrument(level = "trace")]
+    fn copied(&self) -> Option<&dyn Role> {
+        self.copied.as_deref()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::Passive
+    }
+
     #[instrument(level = "trace")]
     async fn action_at_priority(
         &mut self,
@@ -117,47 +180,63 @@ impl Doppelganger {
         Box::pin(async {
             let other_players = game.all_other_players(player);
             // look at player
-            let (target, target_role) =
-                get_role_from_chosen_target_player(game, player, &other_players).await;
+            let Some((target, target_role)) =
+                get_role_from_chosen_target_player(game, player, &other_players).await
+            else {
+                return;
+            };
 
             let target_role = target_role.clone();
-
-            self.copied = Some(target_role.read().await.clone());
+            let copied = target_role.read().await.clone();
+
+            if copy_chain(copied.as_ref()).len() >= MAX_COPY_DEPTH {
+                warn!(
+                    "{} is copied too many times over to copy again",
+                    copied.verbose_id()
+                );
+                game.show_role(player, RoleTarget::Player(target), copied.as_ref())
+                    .await
+                    .unwrap();
+                return;
+            }
+
+            self.copied = Some(copied);
             let copied_role = self.copied.as_mut().unwrap().as_mut();
 
             game.update_player_type(player, &RoleType::Villager, copied_role.role_type());
 
-            player
-                .show_role(
-                    RoleTarget::Player(target),
-                    target_role.read().await.as_ref(),
-                )
-                .await;
-            player
-                .show_role(RoleTarget::Player(player.clone()), self)
+            game.show_role(
+                player,
+                RoleTarget::Player(target),
+                target_role.read().await.as_ref(),
+            )
+            .await;
+            game.show_role(player, RoleTarget::Player(player.clone()), self)
                 .await;
 
             let copied_role = self.copied.as_mut().unwrap().as_mut(); //to allow immutable borrow above
 
-            let pri = copied_role
-                .priorities()
-                .first()
-                .unwrap()
-                .to_owned()
-                .to_owned();
-
-            match copied_role.id().as_str() {
-                "Minion" | "Seer" | "Robber" | "Troublemaker" | "Drunk" => {
-                    copied_role
-                        .action_at_priority(&pri, game, player)
-                        .await
-                        .unwrap();
+            // a copied copier passes its actions on to the role at the end of its chain
+            let resolved = resolve_copy(copied_role);
+            let pris: Vec<ActionPriority> = resolved.priorities().into_iter().cloned().collect();
+            let behaviour = resolved.copy_behaviour();
+
+            match behaviour {
+                CopyBehaviour::ActNow => {
+                    for pri in pris.iter().sorted() {
+                        copied_role
+                            .action_at_priority(pri, game, player)
+                            .await
+                            .unwrap();
+                    }
                 }
-                "Insomniac" | "Mason" | "Werewolf" => {
+                CopyBehaviour::ActLater => {
                     let game_role = game.players().get(player).unwrap().clone();
-                    game.add_night_action_at_priority(&pri, &game_role, Some(player))
+                    for pri in pris.iter() {
+                        game.add_night_action_at_priority(pri, &game_role, Some(player))
+                    }
                 }
-                _ => (),
+                CopyBehaviour::Passive => (),
             };
         })
     }
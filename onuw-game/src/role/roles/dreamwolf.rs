@@ -0,0 +1,37 @@
+use super::werewolf::Werewolf;
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, Role},
+};
+use tracing::instrument;
+
+/// A werewolf who does not wake up, but is still seen by the other werewolves.
+#[derive(Clone, Debug)]
+pub struct DreamWolf;
+
+impl Role for DreamWolf {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "DreamWolf".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Werewolf
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Werewolf::eval_wincon(game, player, dead)
+    }
+}
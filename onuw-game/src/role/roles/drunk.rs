@@ -1,4 +1,7 @@
-use super::villager::Villager;
+use super::{
+    common::{choose_center_card, role_type_of},
+    villager::Villager,
+};
 use crate::{
     game::{GamePlayer, GameRole, ONUWGame},
     playerinterface::roletarget::RoleTarget,
@@ -71,24 +74,23 @@ impl Drunk {
         player: &'a GamePlayer,
     ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
         Box::pin(async {
-            let choice: usize = player
-                .choose_num(&[0, 1, 2])
-                .await
-                .unwrap()
-                .try_into()
-                .unwrap();
+            if game.is_shielded(player) {
+                return;
+            }
+
+            let Some(choice) = choose_center_card(game, player, &[]).await else {
+                return;
+            };
 
             let orig_role = game.players().get(player).unwrap().clone();
             let targetrole = game.centerroles().get(choice).unwrap().clone();
+            let orig_type = role_type_of(self, &orig_role);
+            let target_type = role_type_of(self, &targetrole);
 
             game.change_role(&RoleTarget::Player(player.clone()), &targetrole)
                 .await;
 
-            game.update_player_type(
-                player,
-                &self.role_type(),
-                targetrole.try_read().unwrap().role_type(),
-            );
+            game.update_player_type(player, &orig_type, target_type);
 
             game.change_role(&RoleTarget::Center(choice), &orig_role)
                 .await;
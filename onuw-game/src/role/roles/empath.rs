@@ -0,0 +1,139 @@
+use super::{common::give_random_instruction, villager::Villager};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::{future::join_all, Future};
+use once_cell::sync::Lazy;
+use std::{fmt::Display, pin::Pin};
+use tracing::{instrument, warn};
+
+static ACTIONS: Lazy<ActionFnMap<Empath>> = Lazy::new(|| {
+    [("9E".to_string(), Empath::night_action as ActionFn<Empath>)]
+        .into_iter()
+        .collect()
+});
+
+/// What the app randomly asks everyone to point at while the Empath watches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Question {
+    SuspectWerewolf,
+    SuspectTanner,
+    TrustMost,
+    WantToWin,
+}
+
+impl Question {
+    pub const ALL: [Question; 4] = [
+        Question::SuspectWerewolf,
+        Question::SuspectTanner,
+        Question::TrustMost,
+        Question::WantToWin,
+    ];
+}
+
+impl Display for Question {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Empath;
+
+#[async_trait]
+impl Role for Empath {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Empath".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Empath {
+    /// Everyone else answers a random question by pointing at a player, and the Empath sees
+    /// where they point.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let question = give_random_instruction(game, player, &self.id(), &Question::ALL)
+                .await
+                .unwrap()
+                .to_string();
+
+            let everyone: Vec<_> = game.seats().iter().collect();
+            let pointers: Vec<_> = game.seats().iter().filter(|p| *p != player).collect();
+
+            let answers = join_all(
+                pointers
+                    .iter()
+                    .map(|p| async { (*p, p.point_at(&question, &everyone).await) }),
+            )
+            .await;
+
+            for (pointer, answer) in answers {
+                match answer {
+                    Ok(target) => game
+                        .show_pointed(
+                            player,
+                            RoleTarget::Player(pointer.clone()),
+                            RoleTarget::Player(target),
+                        )
+                        .await
+                        .unwrap(),
+                    Err(e) => warn!("{:?} could not point at anyone: {}", pointer, e),
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,100 @@
+use super::{
+    common::{choose_center_card, give_random_instruction},
+    villager::Villager,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Exposer>> = Lazy::new(|| {
+    [("2K".to_string(), Exposer::night_action as ActionFn<Exposer>)]
+        .into_iter()
+        .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Exposer;
+
+#[async_trait]
+impl Role for Exposer {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Exposer".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Exposer {
+    /// May flip as many center cards face up as the app randomly tells them to.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let count = give_random_instruction(game, player, &self.id(), &[1, 2, 3])
+                .await
+                .unwrap();
+
+            if !player.choose_bool().await.unwrap() {
+                return;
+            }
+
+            let mut flipped = Vec::new();
+            for _ in 0..count {
+                let Some(i) = choose_center_card(game, player, &flipped).await else {
+                    return;
+                };
+
+                game.flip_center_face_up(i);
+                flipped.push(i);
+            }
+        })
+    }
+}
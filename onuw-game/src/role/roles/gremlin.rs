@@ -0,0 +1,140 @@
+use super::{
+    common::{swap_marks, swap_role_with_target_player_with_asker, unshielded},
+    villager::Villager,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Gremlin>> = Lazy::new(|| {
+    [("1M".to_string(), Gremlin::night_action as ActionFn<Gremlin>)]
+        .into_iter()
+        .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Gremlin;
+
+#[async_trait]
+impl Role for Gremlin {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Gremlin".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Gremlin {
+    /// Swaps either the marks or the cards of two other players.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            if !player.choose_bool().await.unwrap() {
+                return;
+            }
+
+            // the Gremlin chooses between swapping marks and swapping cards
+            if player.choose_bool().await.unwrap() {
+                let playerlist: Vec<_> = game
+                    .all_other_players(player)
+                    .into_iter()
+                    .cloned()
+                    .collect();
+                if playerlist.len() < 2 {
+                    return;
+                }
+
+                let target_1 = player
+                    .choose_player(playerlist.iter().collect::<Vec<_>>().as_slice())
+                    .await
+                    .unwrap();
+                let target_2 = player
+                    .choose_player(
+                        playerlist
+                            .iter()
+                            .filter(|v| **v != target_1)
+                            .collect::<Vec<_>>()
+                            .as_slice(),
+                    )
+                    .await
+                    .unwrap();
+
+                swap_marks(game, &target_1, &target_2);
+            } else {
+                let playerlist: Vec<_> = unshielded(game, &game.all_other_players(player))
+                    .into_iter()
+                    .cloned()
+                    .collect();
+                if playerlist.len() < 2 {
+                    return;
+                }
+
+                let target_1 = player
+                    .choose_player(playerlist.iter().collect::<Vec<_>>().as_slice())
+                    .await
+                    .unwrap();
+                swap_role_with_target_player_with_asker(
+                    self,
+                    game,
+                    &target_1,
+                    player,
+                    playerlist
+                        .iter()
+                        .filter(|v| **v != target_1)
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                )
+                .await;
+            }
+        })
+    }
+}
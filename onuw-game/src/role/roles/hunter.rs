@@ -3,7 +3,7 @@ use crate::{
     game::{voteaction::ONUWGameVoteAction, GamePlayer, GameRole, ONUWGame},
     role::{roletype::RoleType, Role},
 };
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use tracing::instrument;
 
 #[derive(Clone, Debug)]
@@ -40,10 +40,10 @@ impl Role for Hunter {
         &self,
         game: &ONUWGame,
         player: &GamePlayer,
-        votes: &HashMap<GamePlayer, GamePlayer>,
+        votes: &BTreeMap<GamePlayer, GamePlayer>,
         dead: &[GamePlayer],
     ) -> Vec<ONUWGameVoteAction> {
-        if dead.iter().any(|v| v.name() == player.name()) {
+        if dead.contains(player) {
             vec![ONUWGameVoteAction::Kill(votes.get(player).unwrap().clone())]
         } else {
             Vec::new()
@@ -2,7 +2,7 @@ use super::villager::Villager;
 use crate::{
     game::{GamePlayer, GameRole, ONUWGame},
     playerinterface::roletarget::RoleTarget,
-    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
 };
 use async_trait::async_trait;
 use futures::Future;
@@ -50,6 +50,11 @@ impl Role for Insomniac {
         ACTIONS.keys().collect()
     }
 
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
     #[instrument(level = "trace")]
     async fn action_at_priority(
         &mut self,
@@ -76,13 +81,11 @@ impl Insomniac {
         Box::pin(async {
             match game.players().get(player).unwrap().try_read() {
                 Ok(role) => {
-                    player
-                        .show_role(RoleTarget::Player(player.clone()), role.as_ref())
+                    game.show_role(player, RoleTarget::Player(player.clone()), role.as_ref())
                         .await
                 }
                 Err(_) => {
-                    player
-                        .show_role(RoleTarget::Player(player.clone()), self)
+                    game.show_role(player, RoleTarget::Player(player.clone()), self)
                         .await
                 }
             };
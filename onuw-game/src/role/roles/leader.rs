@@ -0,0 +1,108 @@
+use super::{common::show_type, groob::Groob, villager::Villager, zerb::Zerb};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Leader>> = Lazy::new(|| {
+    [("2F".to_string(), Leader::night_action as ActionFn<Leader>)]
+        .into_iter()
+        .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Leader;
+
+#[async_trait]
+impl Role for Leader {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Leader".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Self::eval_wincon(game, player, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Leader {
+    /// With both Groob and Zerb in play, the Leader only wins if both of them survive.
+    #[instrument(level = "trace", skip(dead))]
+    pub fn eval_wincon(
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        let pair = [Groob::new().id(), Zerb::new().id()];
+        let in_play = |id: &String| {
+            game.players()
+                .values()
+                .any(|r| r.try_read().is_ok_and(|r| r.effective_id() == *id))
+        };
+
+        if pair.iter().all(in_play) {
+            dead.iter()
+                .all(|(_, r)| !pair.contains(&r.try_read().unwrap().effective_id()))
+        } else {
+            Villager::eval_wincon(game, dead)
+        }
+    }
+
+    /// Sees the thumbs of every alien.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            show_type(self, game, player, &RoleType::Alien).await;
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use super::{
+    common::{get_role_from_chosen_target_player, role_of},
+    villager::Villager,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Marksman>> = Lazy::new(|| {
+    [(
+        "1K".to_string(),
+        Marksman::night_action as ActionFn<Marksman>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Marksman;
+
+#[async_trait]
+impl Role for Marksman {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Marksman".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Marksman {
+    /// Looks at another player's card and at the mark of a third player.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let other_players = game.all_other_players(player);
+            if let Some((target, target_role)) =
+                get_role_from_chosen_target_player(game, player, &other_players).await
+            {
+                game.show_role(
+                    player,
+                    RoleTarget::Player(target.clone()),
+                    role_of(self, target_role).as_ref(),
+                )
+                .await
+                .unwrap();
+
+                let choices: Vec<_> = other_players
+                    .iter()
+                    .copied()
+                    .filter(|p| **p != target)
+                    .collect();
+                if choices.is_empty() {
+                    return;
+                }
+
+                let marked = player.choose_player(&choices).await.unwrap();
+                game.show_mark(
+                    player,
+                    RoleTarget::Player(marked.clone()),
+                    &game.mark_of(&marked),
+                )
+                .await
+                .unwrap();
+            }
+        })
+    }
+}
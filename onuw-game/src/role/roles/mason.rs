@@ -1,7 +1,7 @@
 use super::{common::show_role, villager::Villager};
 use crate::{
     game::{GamePlayer, GameRole, ONUWGame},
-    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
 };
 use async_trait::async_trait;
 use futures::Future;
@@ -56,6 +56,11 @@ impl Role for Mason {
         ACTIONS.keys().collect()
     }
 
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
     #[instrument(level = "trace")]
     async fn action_at_priority(
         &mut self,
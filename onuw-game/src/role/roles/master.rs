@@ -0,0 +1,107 @@
+use super::vampire::Vampire;
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::{collections::BTreeMap, pin::Pin};
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Master>> = Lazy::new(|| {
+    [("1B".to_string(), Master::wake as ActionFn<Master>)]
+        .into_iter()
+        .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Master;
+
+#[async_trait]
+impl Role for Master {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Master".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Vampire
+    }
+
+    /// The Master survives the vote if any other vampire voted for them.
+    #[instrument(level = "trace")]
+    fn protect_from_vote(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        votes: &BTreeMap<GamePlayer, GamePlayer>,
+    ) -> Vec<GamePlayer> {
+        let Some(vampires) = game.players_by_type().get(&RoleType::Vampire) else {
+            return Vec::new();
+        };
+
+        if votes
+            .iter()
+            .any(|(voter, target)| target == player && voter != player && vampires.contains(voter))
+        {
+            vec![player.clone()]
+        } else {
+            Vec::new()
+        }
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Vampire::eval_wincon(game, player, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Master {
+    #[instrument(level = "trace")]
+    fn wake<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            Vampire::wake_vampires(self, game, player).await;
+        })
+    }
+}
@@ -31,6 +31,11 @@ impl Role for Minion {
         RoleType::Other("Minion")
     }
 
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        RoleType::Werewolf.to_string()
+    }
+
     #[instrument(level = "trace", skip(dead))]
     fn win_condition(
         &self,
@@ -46,7 +51,7 @@ impl Role for Minion {
             dead.iter()
                 .all(|(_, r)| r.try_read().unwrap().role_type() != RoleType::Werewolf)
         } else {
-            dead.iter().all(|(p, _)| p.name() != player.name())
+            dead.iter().all(|(p, _)| p != player)
         }
     }
 
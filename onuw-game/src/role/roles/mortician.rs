@@ -0,0 +1,152 @@
+use super::common::{give_random_instruction, role_of, unshielded};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::{fmt::Display, pin::Pin};
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Mortician>> = Lazy::new(|| {
+    [(
+        "2J".to_string(),
+        Mortician::night_action as ActionFn<Mortician>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+/// Which neighbours' cards the app randomly tells the Mortician to look at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    ViewLeft,
+    ViewRight,
+    ViewBoth,
+}
+
+impl Instruction {
+    pub const ALL: [Instruction; 3] = [
+        Instruction::ViewLeft,
+        Instruction::ViewRight,
+        Instruction::ViewBoth,
+    ];
+
+    fn offsets(&self) -> &'static [isize] {
+        match self {
+            Instruction::ViewLeft => &[-1],
+            Instruction::ViewRight => &[1],
+            Instruction::ViewBoth => &[-1, 1],
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Mortician;
+
+#[async_trait]
+impl Role for Mortician {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Mortician".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Other("Mortician")
+    }
+
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        self.id()
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Self::eval_wincon(game, player, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Mortician {
+    /// Looks at the cards of the neighbours the app randomly picks.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let instruction = give_random_instruction(game, player, &self.id(), &Instruction::ALL)
+                .await
+                .unwrap();
+
+            let mut targets: Vec<_> = instruction
+                .offsets()
+                .iter()
+                .filter_map(|offset| game.seat_at_offset(player, *offset))
+                .filter(|p| *p != player)
+                .collect();
+            targets.dedup();
+
+            for target in unshielded(game, &targets) {
+                game.show_role(
+                    player,
+                    RoleTarget::Player(target.clone()),
+                    role_of(self, &game.players()[target]).as_ref(),
+                )
+                .await
+                .unwrap();
+            }
+        })
+    }
+
+    /// Wins if they survive and one of their neighbours dies.
+    #[instrument(level = "trace", skip(dead))]
+    pub fn eval_wincon(
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        let neighbours = game.neighbours(player);
+
+        dead.iter().all(|(v, _)| v != player) && dead.iter().any(|(v, _)| neighbours.contains(&v))
+    }
+}
@@ -0,0 +1,120 @@
+use super::{
+    common::{get_role_from_chosen_target_player, role_of},
+    werewolf::Werewolf,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<MysticWolf>> = Lazy::new(|| {
+    [
+        ("2".to_string(), MysticWolf::wake as ActionFn<MysticWolf>),
+        (
+            "2C".to_string(),
+            MysticWolf::night_action as ActionFn<MysticWolf>,
+        ),
+    ]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct MysticWolf;
+
+#[async_trait]
+impl Role for MysticWolf {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "MysticWolf".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Werewolf
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Werewolf::eval_wincon(game, player, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl MysticWolf {
+    #[instrument(level = "trace")]
+    fn wake<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            Werewolf::wake(self, game, player).await;
+        })
+    }
+
+    /// Looks at another player's card.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let choices = game.all_other_players(player);
+            let Some((target, target_role)) =
+                get_role_from_chosen_target_player(game, player, &choices).await
+            else {
+                return;
+            };
+
+            game.show_role(
+                player,
+                RoleTarget::Player(target),
+                role_of(self, target_role).as_ref(),
+            )
+            .await
+            .unwrap();
+        })
+    }
+}
@@ -0,0 +1,144 @@
+use super::{
+    common::{get_role_from_chosen_target_player, role_of},
+    villager::Villager,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Nostradamus>> = Lazy::new(|| {
+    [(
+        "9D".to_string(),
+        Nostradamus::night_action as ActionFn<Nostradamus>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Nostradamus {
+    became: Option<Box<dyn Role>>,
+}
+
+#[async_trait]
+impl Role for Nostradamus {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self { became: None }
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Nostradamus".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        if let Some(role) = &self.became {
+            role.role_type()
+        } else {
+            RoleType::Villager
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        if let Some(role) = &self.became {
+            role.team()
+        } else {
+            RoleType::Villager.to_string()
+        }
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        if let Some(role) = &self.became {
+            role.win_condition(game, player, dead)
+        } else {
+            Villager::eval_wincon(game, dead)
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Nostradamus {
+    /// Looks at up to three other players' cards and joins the team of the last one seen.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let mut seen: Vec<GamePlayer> = Vec::new();
+            let mut last_seen = None;
+
+            while seen.len() < 3 {
+                if !seen.is_empty() && !player.choose_bool().await.unwrap() {
+                    break;
+                }
+
+                let choices: Vec<_> = game
+                    .all_other_players(player)
+                    .into_iter()
+                    .filter(|p| !seen.contains(*p))
+                    .collect();
+
+                let Some((target, target_role)) =
+                    get_role_from_chosen_target_player(game, player, &choices).await
+                else {
+                    break;
+                };
+                let target_role = role_of(self, target_role);
+
+                game.show_role(
+                    player,
+                    RoleTarget::Player(target.clone()),
+                    target_role.as_ref(),
+                )
+                .await
+                .unwrap();
+
+                seen.push(target);
+                last_seen = Some(target_role);
+            }
+
+            if let Some(role) = last_seen {
+                let old_type = self.role_type();
+                self.became = Some(role);
+                game.update_player_type(player, &old_type, self.role_type());
+            }
+        })
+    }
+}
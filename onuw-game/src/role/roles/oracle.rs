@@ -0,0 +1,148 @@
+use super::{
+    common::{choose_center_card, give_random_instruction, role_of, show_type},
+    villager::Villager,
+};
+use crate::{
+    game::{mark::Mark, GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::{fmt::Display, pin::Pin};
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Oracle>> = Lazy::new(|| {
+    [("1Y".to_string(), Oracle::night_action as ActionFn<Oracle>)]
+        .into_iter()
+        .collect()
+});
+
+/// The questions the app may ask the Oracle, each with its own consequence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Question {
+    /// Answering yes turns the Oracle into an alien.
+    BecomeAlien,
+    /// Answering yes shows the Oracle a center card, but their own card is turned face up.
+    ViewCenter,
+    /// Guessing the app's number shows the Oracle who the aliens are.
+    GuessNumber,
+}
+
+impl Question {
+    pub const ALL: [Question; 3] = [
+        Question::BecomeAlien,
+        Question::ViewCenter,
+        Question::GuessNumber,
+    ];
+}
+
+impl Display for Question {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+const NUMBERS: [isize; 5] = [1, 2, 3, 4, 5];
+
+#[derive(Clone, Debug)]
+pub struct Oracle;
+
+#[async_trait]
+impl Role for Oracle {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Oracle".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Oracle {
+    /// Answers a random question from the app and lives with the consequence.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let question = give_random_instruction(game, player, &self.id(), &Question::ALL)
+                .await
+                .unwrap();
+
+            match question {
+                Question::BecomeAlien => {
+                    if player.choose_bool().await.unwrap() {
+                        game.place_mark(player, Mark::Alien);
+                    }
+                }
+                Question::ViewCenter => {
+                    if !player.choose_bool().await.unwrap() {
+                        return;
+                    }
+
+                    let Some(i) = choose_center_card(game, player, &[]).await else {
+                        return;
+                    };
+
+                    game.show_role(
+                        player,
+                        RoleTarget::Center(i),
+                        role_of(self, &game.centerroles()[i]).as_ref(),
+                    )
+                    .await
+                    .unwrap();
+                    game.flip_face_up(player);
+                }
+                Question::GuessNumber => {
+                    let guess = player.choose_num(&NUMBERS).await.unwrap();
+                    let number = game.draw_random(&self.id(), &NUMBERS).unwrap();
+
+                    if guess == number {
+                        show_type(self, game, player, &RoleType::Alien).await;
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use super::{
+    common::{get_role_from_chosen_target_player, give_random_instruction, role_of},
+    villager::Villager,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Psychic>> = Lazy::new(|| {
+    [("2G".to_string(), Psychic::night_action as ActionFn<Psychic>)]
+        .into_iter()
+        .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Psychic;
+
+#[async_trait]
+impl Role for Psychic {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Psychic".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Psychic {
+    /// Looks at as many other players' cards as the app randomly tells them to.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let count = give_random_instruction(game, player, &self.id(), &[1, 2])
+                .await
+                .unwrap();
+
+            let mut seen = Vec::new();
+            for _ in 0..count {
+                let choices: Vec<_> = game
+                    .all_other_players(player)
+                    .into_iter()
+                    .filter(|p| !seen.contains(*p))
+                    .collect();
+                let Some((target, target_role)) =
+                    get_role_from_chosen_target_player(game, player, &choices).await
+                else {
+                    return;
+                };
+
+                game.show_role(
+                    player,
+                    RoleTarget::Player(target.clone()),
+                    role_of(self, target_role).as_ref(),
+                )
+                .await
+                .unwrap();
+                seen.push(target);
+            }
+        })
+    }
+}
@@ -0,0 +1,189 @@
+use super::{
+    common::{
+        choose_center_card, give_random_instruction, role_type_of,
+        swap_role_with_target_player_with_asker, unshielded,
+    },
+    villager::Villager,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::{fmt::Display, pin::Pin};
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Rascal>> = Lazy::new(|| {
+    [("2H".to_string(), Rascal::night_action as ActionFn<Rascal>)]
+        .into_iter()
+        .collect()
+});
+
+/// The moves the app may tell the Rascal to make. The Rascal may always choose not to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    /// Swap the cards of two other players.
+    SwapTwoPlayers,
+    /// Swap the Rascal's card with a center card without looking at it.
+    SwapWithCenter,
+    /// Swap the cards of the two players sitting next to the Rascal.
+    SwapNeighbours,
+}
+
+impl Instruction {
+    pub const ALL: [Instruction; 3] = [
+        Instruction::SwapTwoPlayers,
+        Instruction::SwapWithCenter,
+        Instruction::SwapNeighbours,
+    ];
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Rascal;
+
+#[async_trait]
+impl Role for Rascal {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Rascal".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Rascal {
+    /// Follows a random instruction from the app to move cards around.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let instruction = give_random_instruction(game, player, &self.id(), &Instruction::ALL)
+                .await
+                .unwrap();
+
+            if !player.choose_bool().await.unwrap() {
+                return;
+            }
+
+            match instruction {
+                Instruction::SwapTwoPlayers => {
+                    let playerlist: Vec<_> = unshielded(game, &game.all_other_players(player))
+                        .into_iter()
+                        .cloned()
+                        .collect();
+                    if playerlist.len() < 2 {
+                        return;
+                    }
+
+                    let target_1 = player
+                        .choose_player(playerlist.iter().collect::<Vec<_>>().as_slice())
+                        .await
+                        .unwrap();
+                    swap_role_with_target_player_with_asker(
+                        self,
+                        game,
+                        &target_1,
+                        player,
+                        playerlist
+                            .iter()
+                            .filter(|v| **v != target_1)
+                            .collect::<Vec<_>>()
+                            .as_slice(),
+                    )
+                    .await;
+                }
+                Instruction::SwapWithCenter => {
+                    if game.is_shielded(player) {
+                        return;
+                    }
+
+                    let Some(choice) = choose_center_card(game, player, &[]).await else {
+                        return;
+                    };
+
+                    let orig_role = game.players().get(player).unwrap().clone();
+                    let targetrole = game.centerroles().get(choice).unwrap().clone();
+                    let orig_type = role_type_of(self, &orig_role);
+                    let target_type = role_type_of(self, &targetrole);
+
+                    game.change_role(&RoleTarget::Player(player.clone()), &targetrole)
+                        .await;
+                    game.update_player_type(player, &orig_type, target_type);
+                    game.change_role(&RoleTarget::Center(choice), &orig_role)
+                        .await;
+                }
+                Instruction::SwapNeighbours => {
+                    let neighbours: Vec<_> = unshielded(game, &game.neighbours(player))
+                        .into_iter()
+                        .cloned()
+                        .collect();
+                    let [one, two] = neighbours.as_slice() else {
+                        return;
+                    };
+
+                    let (role_one, role_two) =
+                        (game.players()[one].clone(), game.players()[two].clone());
+                    let type_one = role_type_of(self, &role_one);
+                    let type_two = role_type_of(self, &role_two);
+
+                    game.change_role(&RoleTarget::Player(one.clone()), &role_two)
+                        .await;
+                    game.update_player_type(one, &type_one, type_two.clone());
+                    game.change_role(&RoleTarget::Player(two.clone()), &role_one)
+                        .await;
+                    game.update_player_type(two, &type_two, type_one);
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use super::common::show_type;
+use crate::{
+    game::{mark::Mark, GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::{instrument, warn};
+
+static ACTIONS: Lazy<ActionFnMap<Renfield>> = Lazy::new(|| {
+    [(
+        "1D".to_string(),
+        Renfield::night_action as ActionFn<Renfield>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Renfield;
+
+#[async_trait]
+impl Role for Renfield {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Renfield".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Other("Renfield")
+    }
+
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        RoleType::Vampire.to_string()
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        if game
+            .players_by_type()
+            .get(&RoleType::Vampire)
+            .is_some_and(|vampires| !vampires.is_empty())
+        {
+            dead.iter()
+                .all(|(_, r)| r.try_read().unwrap().role_type() != RoleType::Vampire)
+        } else {
+            dead.iter().all(|(p, _)| p != player)
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Renfield {
+    /// Shows Renfield the vampires and whom they marked, then places the Mark of the Bat on
+    /// Renfield.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            show_type(self, game, player, &RoleType::Vampire).await;
+
+            for (target, mark) in game.marks().iter() {
+                if *mark == Mark::Vampire {
+                    if let Err(e) = game
+                        .show_mark(player, RoleTarget::Player(target.clone()), mark)
+                        .await
+                    {
+                        warn!("could not show {} the mark of {}: {}", player, target, e);
+                    }
+                }
+            }
+
+            game.place_mark(player, Mark::Bat);
+        })
+    }
+}
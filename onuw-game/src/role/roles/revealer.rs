@@ -0,0 +1,117 @@
+use super::{
+    common::{get_role_from_chosen_target_player, role_of},
+    villager::Villager,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Revealer>> = Lazy::new(|| {
+    [(
+        "9B".to_string(),
+        Revealer::night_action as ActionFn<Revealer>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Revealer;
+
+#[async_trait]
+impl Role for Revealer {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Revealer".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Revealer {
+    /// Flips another player's card face up, unless it turns out to be a Werewolf or the Tanner.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let (target, target_role) = {
+                let choices = game.all_other_players(player);
+                let Some((target, target_role)) =
+                    get_role_from_chosen_target_player(game, player, &choices).await
+                else {
+                    return;
+                };
+                (target, target_role.clone())
+            };
+
+            let target_role = role_of(self, &target_role);
+
+            game.show_role(
+                player,
+                RoleTarget::Player(target.clone()),
+                target_role.as_ref(),
+            )
+            .await
+            .unwrap();
+
+            if target_role.role_type() != RoleType::Werewolf
+                && target_role.effective_id() != "Tanner"
+            {
+                game.flip_face_up(&target);
+            }
+        })
+    }
+}
@@ -78,19 +78,24 @@ impl Robber {
                     .cloned()
                     .collect::<Vec<_>>();
 
-                let (new_role_player, _) = swap_role_with_target_player(
+                if let Some((new_role_player, _)) = swap_role_with_target_player(
                     self,
                     game,
                     player,
                     choices.iter().collect::<Vec<_>>().as_slice(),
                 )
-                .await;
-                player
-                    .show_role(
-                        RoleTarget::Player(player.clone()),
-                        new_role_player.read().await.as_ref(),
-                    )
-                    .await;
+                .await
+                {
+                    // the new card is locked if the Robber robbed back their own card
+                    let new_role = new_role_player.try_read();
+                    let new_role = match &new_role {
+                        Ok(role) => role.as_ref(),
+                        Err(_) => self as &dyn Role,
+                    };
+
+                    game.show_role(player, RoleTarget::Player(player.clone()), new_role)
+                        .await;
+                }
             }
         })
     }
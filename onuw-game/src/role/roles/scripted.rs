@@ -0,0 +1,212 @@
+use super::{
+    common::{
+        choose_center_card, get_role_from_chosen_target_player, role_type_of, show_type,
+        swap_role_with_target_player,
+    },
+    minion::Minion,
+    tanner::Tanner,
+    villager::Villager,
+    werewolf::Werewolf,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{
+        roletype::RoleType,
+        script::{Script, Step, Target, WinCondition},
+        ActionPriority, CopyBehaviour, Role,
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// A role whose night steps and win condition come from a [`Script`].
+#[derive(Clone, Debug)]
+pub struct ScriptedRole {
+    script: Arc<Script>,
+}
+
+#[async_trait]
+impl Role for ScriptedRole {
+    /// A scripted villager that does nothing. Use [`ScriptedRole::from_script`] instead.
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self::from_script(Arc::new(Script::default()))
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        self.script.name.clone()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        self.script.role_type.clone()
+    }
+
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        self.script.team.clone()
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        match self.script.wins {
+            WinCondition::Villager => Villager::eval_wincon(game, dead),
+            WinCondition::Werewolf => Werewolf::eval_wincon(game, player, dead),
+            WinCondition::Minion => Minion::new().win_condition(game, player, dead),
+            WinCondition::Tanner => Tanner::eval_wincon(game, player, dead),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        self.script.priority.iter().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        if self.script.steps.is_empty() {
+            CopyBehaviour::Passive
+        } else {
+            self.script.copy_behaviour
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if self.script.priority.as_ref() == Some(priority) {
+            let script = self.script.clone();
+            for step in script.steps.iter() {
+                if !self.run_step(step, game, player).await {
+                    break;
+                }
+            }
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl ScriptedRole {
+    #[instrument(level = "trace")]
+    pub fn from_script(script: Arc<Script>) -> Self {
+        Self { script }
+    }
+
+    /// Runs one night step, returning whether the night goes on.
+    #[instrument(level = "trace")]
+    async fn run_step(&self, step: &Step, game: &mut ONUWGame, player: &GamePlayer) -> bool {
+        match step {
+            Step::Ask => return player.choose_bool().await.unwrap(),
+            Step::See(role_type) => {
+                show_type(self, game, player, role_type).await;
+            }
+            Step::View(Target::Center(count)) => {
+                let mut seen = Vec::new();
+                while seen.len() < *count {
+                    match choose_center_card(game, player, &seen).await {
+                        Some(i) => seen.push(i),
+                        None => break,
+                    }
+                }
+
+                for i in seen {
+                    let role = game.centerroles()[i].clone();
+                    self.view(game, player, RoleTarget::Center(i), &role).await;
+                }
+            }
+            Step::View(Target::Own) => {
+                let role = game.players()[player].clone();
+                self.view(game, player, RoleTarget::Player(player.clone()), &role)
+                    .await;
+            }
+            Step::View(target) => {
+                let choices = self.targets(game, player, target);
+                let Some((target, role)) =
+                    get_role_from_chosen_target_player(game, player, &choices).await
+                else {
+                    return true;
+                };
+                let role = role.clone();
+                self.view(game, player, RoleTarget::Player(target), &role)
+                    .await;
+            }
+            Step::Swap(Target::Center(_)) => {
+                if game.is_shielded(player) {
+                    return true;
+                }
+                let Some(i) = choose_center_card(game, player, &[]).await else {
+                    return true;
+                };
+
+                let own_role = game.players()[player].clone();
+                let center_role = game.centerroles()[i].clone();
+                let center_type = role_type_of(self, &center_role);
+
+                game.change_role(&RoleTarget::Player(player.clone()), &center_role)
+                    .await;
+                game.update_player_type(player, &self.role_type(), center_type);
+                game.change_role(&RoleTarget::Center(i), &own_role).await;
+            }
+            Step::Swap(target) => {
+                let choices: Vec<GamePlayer> = self
+                    .targets(game, player, target)
+                    .into_iter()
+                    .cloned()
+                    .collect();
+                swap_role_with_target_player(
+                    self,
+                    game,
+                    player,
+                    choices.iter().collect::<Vec<_>>().as_slice(),
+                )
+                .await;
+            }
+        }
+
+        true
+    }
+
+    /// The players a step aimed at `target` can choose from.
+    #[instrument(level = "trace")]
+    fn targets<'a>(
+        &self,
+        game: &'a ONUWGame,
+        player: &GamePlayer,
+        target: &Target,
+    ) -> Vec<&'a GamePlayer> {
+        match target {
+            Target::Neighbour => game.neighbours(player),
+            _ => game.all_other_players(player),
+        }
+    }
+
+    /// Shows `player` the card at `target`. The card is locked if it is this role's own card.
+    #[instrument(level = "trace")]
+    async fn view(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        target: RoleTarget,
+        role: &GameRole,
+    ) {
+        match role.try_read() {
+            Ok(role) => game.show_role(player, target, role.as_ref()).await,
+            Err(_) => game.show_role(player, target, self).await,
+        }
+        .unwrap();
+    }
+}
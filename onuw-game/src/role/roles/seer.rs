@@ -1,4 +1,7 @@
-use super::{common::get_role_from_chosen_target_player, villager::Villager};
+use super::{
+    common::{choose_center_card, get_role_from_chosen_target_player, role_of},
+    villager::Villager,
+};
 use crate::{
     game::{GamePlayer, GameRole, ONUWGame},
     playerinterface::roletarget::RoleTarget,
@@ -71,53 +74,40 @@ impl Seer {
         player: &'a GamePlayer,
     ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
         Box::pin(async {
-            if player.choose_bool().await.unwrap() {
+            if game.centerroles().is_empty() || player.choose_bool().await.unwrap() {
                 let other_players = game.all_other_players(player);
                 // look at player
-                let (target, target_role) =
-                    get_role_from_chosen_target_player(game, player, &other_players).await;
+                let Some((target, target_role)) =
+                    get_role_from_chosen_target_player(game, player, &other_players).await
+                else {
+                    return;
+                };
 
-                player
-                    .show_role(
-                        RoleTarget::Player(target),
-                        target_role.read().await.as_ref(),
-                    )
-                    .await;
+                game.show_role(
+                    player,
+                    RoleTarget::Player(target),
+                    role_of(self, target_role).as_ref(),
+                )
+                .await;
             } else {
                 // look at two center
-                let one: usize = player
-                    .choose_num(&[0, 1, 2])
-                    .await
-                    .unwrap()
-                    .try_into()
-                    .unwrap();
+                let mut seen = Vec::new();
 
-                let two: usize = player
-                    .choose_num(
-                        (0..=2)
-                            .filter(|v| v != &one)
-                            .map(|v| v.try_into().unwrap())
-                            .collect::<Vec<_>>()
-                            .as_slice(),
-                    )
-                    .await
-                    .unwrap()
-                    .try_into()
-                    .unwrap();
-
-                player
-                    .show_role(
-                        RoleTarget::Center(one),
-                        game.centerroles()[one].read().await.as_ref(),
-                    )
-                    .await;
+                while seen.len() < 2 {
+                    match choose_center_card(game, player, &seen).await {
+                        Some(i) => seen.push(i),
+                        None => break,
+                    }
+                }
 
-                player
-                    .show_role(
-                        RoleTarget::Center(two),
-                        game.centerroles()[two].read().await.as_ref(),
+                for i in seen {
+                    game.show_role(
+                        player,
+                        RoleTarget::Center(i),
+                        role_of(self, &game.centerroles()[i]).as_ref(),
                     )
                     .await;
+                }
             }
         })
     }
@@ -0,0 +1,110 @@
+use super::{
+    common::{role_of, show_type, unshielded},
+    minion::Minion,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Squire>> = Lazy::new(|| {
+    [("3C".to_string(), Squire::night_action as ActionFn<Squire>)]
+        .into_iter()
+        .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Squire;
+
+#[async_trait]
+impl Role for Squire {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Squire".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Other("Squire")
+    }
+
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        RoleType::Werewolf.to_string()
+    }
+
+    /// Wins with the werewolves, just like the Minion.
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Minion::new().win_condition(game, player, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Squire {
+    /// Sees the werewolves and may then look at their cards.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            if show_type(self, game, player, &RoleType::Werewolf).await == 0
+                || !player.choose_bool().await.unwrap()
+            {
+                return;
+            }
+
+            let werewolves: Vec<_> = game.players_by_type()[&RoleType::Werewolf]
+                .iter()
+                .filter(|p| *p != player)
+                .collect();
+
+            for target in unshielded(game, &werewolves) {
+                game.show_role(
+                    player,
+                    RoleTarget::Player(target.clone()),
+                    role_of(self, &game.players()[target]).as_ref(),
+                )
+                .await
+                .unwrap();
+            }
+        })
+    }
+}
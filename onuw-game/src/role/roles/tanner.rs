@@ -1,8 +1,7 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use crate::{
     game::{GamePlayer, GameRole, ONUWGame},
-    playerinterface::PlayerInterface,
     role::{roletype::RoleType, Role},
 };
 use tracing::instrument;
@@ -26,6 +25,11 @@ impl Role for Tanner {
         RoleType::Villager
     }
 
+    #[instrument(level = "trace")]
+    fn team(&self) -> String {
+        self.id()
+    }
+
     #[instrument(level = "trace", skip(dead))]
     fn win_condition(
         &self,
@@ -41,10 +45,10 @@ impl Role for Tanner {
         &self,
         game: &ONUWGame,
         player: &GamePlayer,
-        winners: &'a [(&'a dyn PlayerInterface, &'a dyn Role)],
-        votes: &'a HashMap<GamePlayer, GamePlayer>,
-    ) -> Vec<&'a dyn PlayerInterface> {
-        if winners.iter().any(|(v, _)| v.name() == player.name()) {
+        winners: &'a [(&'a GamePlayer, &'a dyn Role)],
+        votes: &'a BTreeMap<GamePlayer, GamePlayer>,
+    ) -> Vec<&'a GamePlayer> {
+        if winners.iter().any(|(v, _)| *v == player) {
             winners
                 .iter()
                 .filter(|(_, r)| {
@@ -53,7 +57,7 @@ impl Role for Tanner {
                 .map(|(p, _)| p.to_owned())
                 .collect()
         } else {
-            Role::prevent_win(self, game, player, winners, votes)
+            Vec::new()
         }
     }
 }
@@ -65,6 +69,6 @@ impl Tanner {
         player: &GamePlayer,
         dead: &[(GamePlayer, GameRole)],
     ) -> bool {
-        dead.iter().any(|(v, _)| v.name() == player.name())
+        dead.iter().any(|(v, _)| v == player)
     }
 }
@@ -1,4 +1,7 @@
-use super::{common::swap_role_with_target_player_with_asker, villager::Villager};
+use super::{
+    common::{swap_role_with_target_player_with_asker, unshielded},
+    villager::Villager,
+};
 use crate::{
     game::{GamePlayer, GameRole, ONUWGame},
     role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
@@ -74,26 +77,27 @@ impl Troublemaker {
     ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
         Box::pin(async {
             if player.choose_bool().await.unwrap() {
-                let playerlist: Vec<_> = game
-                    .all_other_players(player)
+                let playerlist: Vec<_> = unshielded(game, &game.all_other_players(player))
                     .into_iter()
                     .cloned()
                     .collect();
 
+                if playerlist.len() < 2 {
+                    return;
+                }
+
                 let target_1 = player
                     .choose_player(playerlist.iter().collect::<Vec<_>>().as_slice())
                     .await
                     .unwrap();
-                let target_1_role = game.players().get(&target_1).unwrap().clone();
-
                 swap_role_with_target_player_with_asker(
-                    target_1_role.try_read().unwrap().as_ref(),
+                    self,
                     game,
                     &target_1,
                     player,
                     playerlist
                         .iter()
-                        .filter(|v| v.as_ref() != target_1.as_ref())
+                        .filter(|v| **v != target_1)
                         .collect::<Vec<_>>()
                         .as_slice(),
                 )
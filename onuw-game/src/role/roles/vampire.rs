@@ -0,0 +1,138 @@
+use super::common::show_type;
+use crate::{
+    game::{mark::Mark, GamePlayer, GameRole, ONUWGame},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::{collections::BTreeSet, pin::Pin};
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Vampire>> = Lazy::new(|| {
+    [("1B".to_string(), Vampire::wake as ActionFn<Vampire>)]
+        .into_iter()
+        .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Vampire;
+
+#[async_trait]
+impl Role for Vampire {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Vampire".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Vampire
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Vampire::eval_wincon(game, player, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Vampire {
+    #[instrument(level = "trace")]
+    fn wake<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            Self::wake_vampires(self, game, player).await;
+        })
+    }
+
+    /// Shows `player` the other vampires and, unless another vampire already did so tonight,
+    /// lets them place the Mark of the Vampire on a player who is not a vampire.
+    #[instrument(level = "trace")]
+    pub(crate) async fn wake_vampires(
+        calling_role: &dyn Role,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) {
+        show_type(calling_role, game, player, &RoleType::Vampire).await;
+
+        if game.marks().values().any(|m| *m == Mark::Vampire) {
+            return;
+        }
+
+        let Some(target) = choose_non_vampire(game, player).await else {
+            return;
+        };
+
+        game.place_mark(&target, Mark::Vampire);
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    pub fn eval_wincon(
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        dead.iter()
+            .all(|(_, r)| r.try_read().unwrap().role_type() != RoleType::Vampire)
+    }
+}
+
+/// Lets `player` choose another player who is not a vampire, if there is one.
+#[instrument(level = "trace")]
+pub(crate) async fn choose_non_vampire(game: &ONUWGame, player: &GamePlayer) -> Option<GamePlayer> {
+    let vampires = game
+        .players_by_type()
+        .get(&RoleType::Vampire)
+        .cloned()
+        .unwrap_or_else(BTreeSet::new);
+
+    let choices: Vec<_> = game
+        .all_other_players(player)
+        .into_iter()
+        .filter(|p| !vampires.contains(*p))
+        .collect();
+
+    if choices.is_empty() {
+        None
+    } else {
+        Some(player.choose_player(&choices).await.unwrap())
+    }
+}
@@ -0,0 +1,108 @@
+use super::{common::role_type_of, villager::Villager};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<VillageIdiot>> = Lazy::new(|| {
+    [(
+        "7B".to_string(),
+        VillageIdiot::night_action as ActionFn<VillageIdiot>,
+    )]
+    .into_iter()
+    .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct VillageIdiot;
+
+#[async_trait]
+impl Role for VillageIdiot {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "VillageIdiot".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl VillageIdiot {
+    /// Moves every other player's card one seat along, in either direction, or leaves them be.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let shift = player.choose_num(&[-1, 0, 1]).await.unwrap();
+            if shift == 0 {
+                return;
+            }
+
+            let others: Vec<GamePlayer> = game
+                .seats()
+                .iter()
+                .filter(|p| *p != player && !game.is_shielded(p))
+                .cloned()
+                .collect();
+            let roles: Vec<GameRole> = others.iter().map(|p| game.players()[p].clone()).collect();
+
+            let types: Vec<RoleType> = roles.iter().map(|r| role_type_of(self, r)).collect();
+
+            let n = others.len();
+            for (i, target) in others.iter().enumerate() {
+                // the card at seat `j` moves to seat `j + shift`
+                let from = (i as isize - shift).rem_euclid(n as isize) as usize;
+
+                game.change_role(&RoleTarget::Player(target.clone()), &roles[from])
+                    .await;
+                game.update_player_type(target, &types[i], types[from].clone());
+            }
+        })
+    }
+}
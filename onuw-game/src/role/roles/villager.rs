@@ -46,15 +46,18 @@ impl Role for Villager {
 impl Villager {
     #[instrument(level = "trace", skip(dead))]
     pub fn eval_wincon(game: &ONUWGame, dead: &[(GamePlayer, GameRole)]) -> bool {
+        let is_enemy = |t: &RoleType| {
+            *t == RoleType::Werewolf || *t == RoleType::Vampire || *t == RoleType::Alien
+        };
+
         (game
             .players_by_type()
-            .get(&RoleType::Werewolf)
-            .map(|entry| entry.is_empty())
-            .unwrap_or(true)
+            .iter()
+            .all(|(t, entry)| !is_enemy(t) || entry.is_empty())
             && dead.is_empty())
             || (dead
                 .iter()
-                .any(|(_, r)| r.try_read().unwrap().role_type() == RoleType::Werewolf))
+                .any(|(_, r)| is_enemy(&r.try_read().unwrap().role_type())))
     }
 }
 
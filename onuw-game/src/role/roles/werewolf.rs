@@ -1,8 +1,8 @@
-use super::common::show_type;
+use super::common::{choose_center_card, role_of, show_type};
 use crate::{
     game::{GamePlayer, GameRole, ONUWGame},
     playerinterface::roletarget::RoleTarget,
-    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, CopyBehaviour, Role},
 };
 use async_trait::async_trait;
 use futures::Future;
@@ -47,8 +47,7 @@ impl Role for Werewolf {
         player: &GamePlayer,
         dead: &[(GamePlayer, GameRole)],
     ) -> bool {
-        dead.iter()
-            .all(|(_, r)| r.try_read().unwrap().role_type() != RoleType::Werewolf)
+        Self::eval_wincon(game, player, dead)
     }
 
     #[instrument(level = "trace")]
@@ -56,6 +55,11 @@ impl Role for Werewolf {
         ACTIONS.keys().collect()
     }
 
+    #[instrument(level = "trace")]
+    fn copy_behaviour(&self) -> CopyBehaviour {
+        CopyBehaviour::ActLater
+    }
+
     #[instrument(level = "trace")]
     async fn action_at_priority(
         &mut self,
@@ -80,21 +84,36 @@ impl Werewolf {
         player: &'a GamePlayer,
     ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
         Box::pin(async {
-            let alone = show_type(self, game, player, &RoleType::Werewolf).await == 0;
-
-            if *game.options().lone_wolf() && alone {
-                let i: usize = (player.choose_num(&[0, 1, 2]).await.unwrap())
-                    .try_into()
-                    .unwrap();
-                player
-                    .show_role(
-                        RoleTarget::Center(i),
-                        game.centerroles().get(i).unwrap().read().await.as_ref(),
-                    )
-                    .await;
-            };
+            Self::wake(self, game, player).await;
         })
     }
+
+    /// Shows `player` the other werewolves, letting a lone wolf view a center card.
+    #[instrument(level = "trace")]
+    pub(crate) async fn wake(calling_role: &dyn Role, game: &ONUWGame, player: &GamePlayer) {
+        let alone = show_type(calling_role, game, player, &RoleType::Werewolf).await == 0;
+
+        if *game.options().lone_wolf() && alone {
+            if let Some(i) = choose_center_card(game, player, &[]).await {
+                game.show_role(
+                    player,
+                    RoleTarget::Center(i),
+                    role_of(calling_role, &game.centerroles()[i]).as_ref(),
+                )
+                .await;
+            }
+        };
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    pub fn eval_wincon(
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        dead.iter()
+            .all(|(_, r)| r.try_read().unwrap().role_type() != RoleType::Werewolf)
+    }
 }
 
 
@@ -0,0 +1,121 @@
+use super::{
+    common::{choose_center_card, role_of, role_type_of},
+    villager::Villager,
+};
+use crate::{
+    game::{GamePlayer, GameRole, ONUWGame},
+    playerinterface::roletarget::RoleTarget,
+    role::{roletype::RoleType, ActionFn, ActionFnMap, ActionPriority, Role},
+};
+use async_trait::async_trait;
+use futures::Future;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use std::pin::Pin;
+use tracing::instrument;
+
+static ACTIONS: Lazy<ActionFnMap<Witch>> = Lazy::new(|| {
+    [("6B".to_string(), Witch::night_action as ActionFn<Witch>)]
+        .into_iter()
+        .collect()
+});
+
+#[derive(Clone, Debug)]
+pub struct Witch;
+
+#[async_trait]
+impl Role for Witch {
+    #[instrument(level = "trace")]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(level = "trace")]
+    fn id(&self) -> String {
+        "Witch".to_string()
+    }
+
+    #[instrument(level = "trace")]
+    fn role_type(&self) -> RoleType {
+        RoleType::Villager
+    }
+
+    #[instrument(level = "trace", skip(dead))]
+    fn win_condition(
+        &self,
+        game: &ONUWGame,
+        player: &GamePlayer,
+        dead: &[(GamePlayer, GameRole)],
+    ) -> bool {
+        Villager::eval_wincon(game, dead)
+    }
+
+    #[instrument(level = "trace")]
+    fn priorities(&self) -> Vec<&ActionPriority> {
+        ACTIONS.keys().collect()
+    }
+
+    #[instrument(level = "trace")]
+    async fn action_at_priority(
+        &mut self,
+        priority: &ActionPriority,
+        game: &mut ONUWGame,
+        player: &GamePlayer,
+    ) -> Result<(), String> {
+        if let Some(action) = ACTIONS.get(priority) {
+            action(self, game, player).await;
+            Ok(())
+        } else {
+            Err(format!("No action for {:?} at priority {}", self, priority))
+        }
+    }
+}
+
+impl Witch {
+    /// Looks at a center card and may swap it with any player's card, including her own.
+    #[instrument(level = "trace")]
+    fn night_action<'a>(
+        &'a mut self,
+        game: &'a mut ONUWGame,
+        player: &'a GamePlayer,
+    ) -> Pin<Box<dyn Future<Output = ()> + 'a + Send>> {
+        Box::pin(async {
+            let Some(i) = choose_center_card(game, player, &[]).await else {
+                return;
+            };
+
+            let center_role = game.centerroles()[i].clone();
+
+            game.show_role(
+                player,
+                RoleTarget::Center(i),
+                role_of(self, &center_role).as_ref(),
+            )
+            .await
+            .unwrap();
+
+            if !player.choose_bool().await.unwrap() {
+                return;
+            }
+
+            let target = {
+                let choices: Vec<_> = game
+                    .players()
+                    .keys()
+                    .filter(|p| !game.is_shielded(p))
+                    .sorted()
+                    .collect();
+                player.choose_player(&choices).await.unwrap()
+            };
+
+            let target_role = game.players()[&target].clone();
+            let old_type = role_type_of(self, &target_role);
+            let new_type = role_type_of(self, &center_role);
+
+            game.change_role(&RoleTarget::Player(target.clone()), &center_role)
+                .await;
+            game.update_player_type(&target, &old_type, new_type);
+            game.change_role(&RoleTarget::Center(i), &target_role).await;
+        })
+    }
+}
@@ -1,14 +1,19 @@
 use std::fmt::Display;
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum RoleType {
     Villager,
     Werewolf,
+    Vampire,
+    Alien,
     Other(&'static str),
 }
 
 impl Display for RoleType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:#?}", self)
+        match self {
+            RoleType::Other(name) => write!(f, "{}", name),
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
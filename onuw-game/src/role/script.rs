@@ -0,0 +1,247 @@
+//! A small rules language for describing roles without writing Rust. A script is a list of
+//! lines, one statement each, with `#` starting a comment:
+//!
+//! ```text
+//! role Lookout         # the role's name
+//! type Villager        # Villager, Werewolf, Vampire or Alien
+//! team Villager        # optional, defaults to the type
+//! max 2                # optional, how many copies can be in a game, defaults to 1
+//! wins villager        # villager, werewolf, minion or tanner
+//! copied later         # optional, see CopyBehaviour, defaults to now
+//! night 5E             # the priority the night steps below run at
+//! ask                  # the player may stop here
+//! view neighbour       # look at a card: player, neighbour, own or center [count]
+//! swap player          # swap cards: player, neighbour or center
+//! see Werewolf         # see every player of a type
+//! ```
+//!
+//! Scripts cannot loop or touch anything but the steps above, so a scripted role always
+//! finishes its turn. The bundled scripts in [`SCRIPTS`] are registered in
+//! [`ROLES`](super::roles::ROLES) next to the built-in roles, followed by the `.role` files in
+//! the directory named by [`SCRIPTS_DIR_VAR`], if it is set.
+
+use super::{roletype::RoleType, ActionPriority, CopyBehaviour};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The scripted roles that ship with the game.
+pub const SCRIPTS: &[&str] = &[
+    include_str!("scripts/lookout.role"),
+    include_str!("scripts/smuggler.role"),
+];
+
+/// The environment variable naming a directory of `.role` files to load at startup.
+pub const SCRIPTS_DIR_VAR: &str = "ONUW_SCRIPTS";
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ScriptError {
+    #[error("line {line}: {message}")]
+    Syntax { line: usize, message: String },
+    #[error("The script does not name its role.")]
+    MissingName,
+    #[error("The script does not say how {role} wins.")]
+    MissingWinCondition { role: String },
+    #[error("{role} has night steps but no night priority.")]
+    MissingPriority { role: String },
+    #[error("There already is a role called {role}.")]
+    NameTaken { role: String },
+}
+
+/// The card a night step looks at or swaps with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Target {
+    /// Any other player.
+    Player,
+    /// The player to the left or right.
+    Neighbour,
+    /// The player's own card.
+    Own,
+    /// This many center cards.
+    Center(usize),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// Asks the player whether to go on. The rest of the night is skipped if they say no.
+    Ask,
+    See(RoleType),
+    View(Target),
+    Swap(Target),
+}
+
+/// The win conditions of the built-in roles a scripted role can borrow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WinCondition {
+    Villager,
+    Werewolf,
+    Minion,
+    Tanner,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Script {
+    pub name: String,
+    pub role_type: RoleType,
+    pub team: String,
+    pub max_amt: usize,
+    pub wins: WinCondition,
+    pub copy_behaviour: CopyBehaviour,
+    pub priority: Option<ActionPriority>,
+    pub steps: Vec<Step>,
+}
+
+impl Default for Script {
+    fn default() -> Self {
+        Self {
+            name: "Scripted".to_string(),
+            role_type: RoleType::Villager,
+            team: RoleType::Villager.to_string(),
+            max_amt: 1,
+            wins: WinCondition::Villager,
+            copy_behaviour: CopyBehaviour::ActNow,
+            priority: None,
+            steps: Vec::new(),
+        }
+    }
+}
+
+impl Script {
+    pub fn parse(source: &str) -> Result<Self, ScriptError> {
+        let mut name = None;
+        let mut role_type = RoleType::Villager;
+        let mut team = None;
+        let mut max_amt = 1;
+        let mut wins = None;
+        let mut copy_behaviour = CopyBehaviour::ActNow;
+        let mut priority = None;
+        let mut steps = Vec::new();
+
+        for (i, line) in source.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let Some((keyword, args)) = words.split_first() else {
+                continue;
+            };
+            let error = |message: &str| ScriptError::Syntax {
+                line: i + 1,
+                message: message.to_string(),
+            };
+            let single = || match args {
+                [arg] => Ok(*arg),
+                _ => Err(error(&format!("{} takes exactly one argument", keyword))),
+            };
+
+            match *keyword {
+                "role" => {
+                    let arg = single()?;
+                    if !arg.chars().all(|c| c.is_ascii_alphanumeric()) {
+                        return Err(error("role names are letters and digits only"));
+                    }
+                    name = Some(arg.to_string());
+                }
+                "type" => role_type = parse_role_type(single()?).ok_or(error("unknown type"))?,
+                "team" => team = Some(single()?.to_string()),
+                "max" => {
+                    max_amt = single()?
+                        .parse()
+                        .ok()
+                        .filter(|amt| *amt > 0)
+                        .ok_or(error("max is a positive number"))?;
+                }
+                "wins" => {
+                    wins = Some(match single()? {
+                        "villager" => WinCondition::Villager,
+                        "werewolf" => WinCondition::Werewolf,
+                        "minion" => WinCondition::Minion,
+                        "tanner" => WinCondition::Tanner,
+                        _ => return Err(error("unknown win condition")),
+                    });
+                }
+                "copied" => {
+                    copy_behaviour = match single()? {
+                        "now" => CopyBehaviour::ActNow,
+                        "later" => CopyBehaviour::ActLater,
+                        _ => return Err(error("copied is either now or later")),
+                    };
+                }
+                "night" => priority = Some(single()?.to_string()),
+                "ask" if args.is_empty() => steps.push(Step::Ask),
+                "see" => {
+                    steps.push(Step::See(
+                        parse_role_type(single()?).ok_or(error("unknown type"))?,
+                    ));
+                }
+                "view" => steps.push(Step::View(
+                    parse_target(args).ok_or(error("unknown target"))?,
+                )),
+                "swap" => match parse_target(args) {
+                    Some(Target::Own) | None => return Err(error("unknown target")),
+                    Some(Target::Center(count)) if count != 1 => {
+                        return Err(error("only one center card can be swapped"))
+                    }
+                    Some(target) => steps.push(Step::Swap(target)),
+                },
+                _ => return Err(error(&format!("unknown statement {}", keyword))),
+            }
+        }
+
+        let name = name.ok_or(ScriptError::MissingName)?;
+        if priority.is_none() && !steps.is_empty() {
+            return Err(ScriptError::MissingPriority { role: name });
+        }
+
+        Ok(Self {
+            team: team.unwrap_or_else(|| role_type.to_string()),
+            wins: wins.ok_or_else(|| ScriptError::MissingWinCondition { role: name.clone() })?,
+            name,
+            role_type,
+            max_amt,
+            copy_behaviour,
+            priority,
+            steps,
+        })
+    }
+}
+
+/// Reads and parses every `.role` file in `dir`, in file name order.
+pub fn load_scripts(
+    dir: impl AsRef<Path>,
+) -> std::io::Result<Vec<(PathBuf, Result<Script, ScriptError>)>> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "role") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let script = Script::parse(&std::fs::read_to_string(&path)?);
+            Ok((path, script))
+        })
+        .collect()
+}
+
+fn parse_role_type(word: &str) -> Option<RoleType> {
+    match word {
+        "Villager" => Some(RoleType::Villager),
+        "Werewolf" => Some(RoleType::Werewolf),
+        "Vampire" => Some(RoleType::Vampire),
+        "Alien" => Some(RoleType::Alien),
+        _ => None,
+    }
+}
+
+fn parse_target(words: &[&str]) -> Option<Target> {
+    match words {
+        ["player"] => Some(Target::Player),
+        ["neighbour"] => Some(Target::Neighbour),
+        ["own"] => Some(Target::Own),
+        ["center"] => Some(Target::Center(1)),
+        ["center", count] => count.parse().ok().filter(|c| *c > 0).map(Target::Center),
+        _ => None,
+    }
+}
@@ -0,0 +1,603 @@
+use super::{init_logging, new_game, players, run_night, testplayer::Response};
+use crate::{
+    game::{
+        mark::Mark,
+        options::Options,
+        snapshot::{Observation, ObservedTarget, RandomDraw},
+        PlayerId,
+    },
+    role::{
+        roles::{
+            alien::{self, Alien},
+            blob::{Blob, Shape},
+            bodysnatcher::BodySnatcher,
+            cow::Cow,
+            exposer::Exposer,
+            gremlin::Gremlin,
+            groob::Groob,
+            hunter::Hunter,
+            leader::Leader,
+            minion::Minion,
+            mortician::{self, Mortician},
+            oracle::{Oracle, Question},
+            psychic::Psychic,
+            rascal::{self, Rascal},
+            seer::Seer,
+            syntheticalien::SyntheticAlien,
+            tanner::Tanner,
+            villager::Villager,
+            werewolf::Werewolf,
+            zerb::Zerb,
+        },
+        roletype::RoleType,
+        Role,
+    },
+};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+/// Finds a seed whose first random draw from `choices` is `wanted`, for games in which nothing
+/// else is random.
+fn seed_for<T: PartialEq>(choices: &[T], wanted: &T) -> u64 {
+    (0..)
+        .find(|s| choices.choose(&mut StdRng::seed_from_u64(*s)) == Some(wanted))
+        .unwrap()
+}
+
+#[tokio::test(start_paused = true)]
+async fn seed_replays_deal() {
+    init_logging();
+
+    let players = players(4);
+    let mut deals = Vec::new();
+    for _ in 0..2 {
+        let game = new_game(
+            &players,
+            vec![
+                Box::new(Seer::new()),
+                Box::new(Villager::new()),
+                Box::new(Werewolf::new()),
+                Box::new(Minion::new()),
+                Box::new(Hunter::new()),
+                Box::new(Tanner::new()),
+                Box::new(Alien::new()),
+            ],
+            Options::new().with_seed(42),
+        )
+        .await;
+
+        let snapshot = game.snapshot().await;
+        assert_eq!(snapshot.seed, 42);
+        deals.push((snapshot.players, snapshot.center));
+    }
+
+    assert_eq!(deals[0], deals[1]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn aliens_turn_a_player() {
+    init_logging();
+
+    let seed = seed_for(&alien::Instruction::ALL, &alien::Instruction::TurnAlien);
+
+    let players = players(4);
+    // whichever alien wakes first picks the player
+    for i in [0, 1] {
+        players[i]
+            .push_choice(vec![Response::Player(players[2].clone())])
+            .await
+            .unwrap();
+    }
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Alien::new()),
+            Box::new(Alien::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles().with_seed(seed),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let turned = game.seats()[2].clone();
+    assert_eq!(game.mark_of(&turned), Mark::Alien);
+    assert!(game.players_by_type()[&RoleType::Alien].contains(&turned));
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(
+        snapshot.random_draws,
+        [RandomDraw {
+            purpose: "Alien".to_string(),
+            result: "TurnAlien".to_string(),
+        }]
+    );
+    assert_eq!(
+        snapshot
+            .players
+            .iter()
+            .map(|p| p.team.as_str())
+            .collect::<Vec<_>>(),
+        ["Alien", "Alien", "Alien", "Villager"]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn groob_and_zerb() {
+    init_logging();
+
+    let players = players(4);
+    for (voter, target) in [(0, 1), (1, 0), (2, 1), (3, 1)] {
+        players[voter]
+            .push_choice(vec![Response::Player(players[target].clone())])
+            .await
+            .unwrap();
+    }
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Groob::new()),
+            Box::new(Zerb::new()),
+            Box::new(SyntheticAlien::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    // the synthetic alien survived, so only the groob and the villager win
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(1)]);
+    assert_eq!(reveal.winners, [PlayerId(0), PlayerId(3)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn cow_and_leader() {
+    init_logging();
+
+    let seed = seed_for(&alien::Instruction::ALL, &alien::Instruction::ViewCenter);
+
+    let players = players(4);
+    players[1]
+        .push_choice(vec![Response::Num(0)])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Cow::new()),
+            Box::new(Alien::new()),
+            Box::new(Villager::new()),
+            Box::new(Leader::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles().with_seed(seed),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let alien = Observation::RoleType {
+        target: ObservedTarget::Player(PlayerId(1)),
+        role_type: "Alien".to_string(),
+    };
+    for i in [0, 3] {
+        let player = game.seats()[i].clone();
+        assert!(game
+            .player_view(&player)
+            .unwrap()
+            .observations
+            .contains(&alien));
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn oracle() {
+    init_logging();
+
+    let seed = seed_for(&Question::ALL, &Question::BecomeAlien);
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![Response::Bool(true)])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Oracle::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles().with_seed(seed),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let oracle = game.seats()[0].clone();
+    assert_eq!(game.mark_of(&oracle), Mark::Alien);
+    assert_eq!(
+        game.player_view(&oracle).unwrap().observations,
+        [
+            Observation::Instruction {
+                instruction: "BecomeAlien".to_string(),
+            },
+            Observation::Mark {
+                target: ObservedTarget::Player(PlayerId(0)),
+                mark: "Alien".to_string(),
+            },
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn psychic() {
+    init_logging();
+
+    let seed = seed_for(&[1, 2], &2);
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Player(players[1].clone()),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Psychic::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles().with_seed(seed),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let psychic = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&psychic).unwrap().observations,
+        [
+            Observation::Instruction {
+                instruction: "2".to_string(),
+            },
+            Observation::Role {
+                target: ObservedTarget::Player(PlayerId(1)),
+                role: "Villager".to_string(),
+            },
+            Observation::Role {
+                target: ObservedTarget::Player(PlayerId(2)),
+                role: "Werewolf".to_string(),
+            },
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn rascal_swaps_neighbours() {
+    init_logging();
+
+    let seed = seed_for(
+        &rascal::Instruction::ALL,
+        &rascal::Instruction::SwapNeighbours,
+    );
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![Response::Bool(true)])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Rascal::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles().with_seed(seed),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let snapshot = game.snapshot().await;
+    let mut roles: Vec<_> = snapshot
+        .players
+        .iter()
+        .map(|p| (p.name.as_str(), p.role.as_str()))
+        .collect();
+    roles.sort();
+    assert_eq!(
+        roles,
+        [("0", "Rascal"), ("1", "Werewolf"), ("2", "Villager")]
+    );
+}
+
+/// The Gremlin gives the Rascal's card to a player the Rascal then swaps.
+#[tokio::test(start_paused = true)]
+async fn rascal_after_gremlin() {
+    init_logging();
+
+    let seed = seed_for(
+        &rascal::Instruction::ALL,
+        &rascal::Instruction::SwapTwoPlayers,
+    );
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Player(players[1].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+    players[3]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Player(players[0].clone()),
+            Response::Bool(false),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Rascal::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Villager::new()),
+            Box::new(Gremlin::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles().with_seed(seed),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(
+        snapshot
+            .players
+            .iter()
+            .map(|p| p.role.as_str())
+            .collect::<Vec<_>>(),
+        ["Werewolf", "Villager", "Rascal", "Gremlin"]
+    );
+    assert_eq!(
+        game.players_by_type()[&RoleType::Werewolf]
+            .iter()
+            .collect::<Vec<_>>(),
+        [&game.seats()[0]]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn blob() {
+    init_logging();
+
+    let seed = seed_for(&Shape::ALL, &Shape::Right);
+
+    let players = players(4);
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Blob::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles().with_seed(seed),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    // the player to the right is told they are part of the blob, the one to the left is not
+    let told = Observation::Instruction {
+        instruction: "Right".to_string(),
+    };
+    for (i, in_blob) in [(1, true), (3, false)] {
+        let player = game.seats()[i].clone();
+        assert_eq!(
+            game.player_view(&player)
+                .unwrap()
+                .observations
+                .contains(&told),
+            in_blob
+        );
+    }
+
+    for (voter, target) in [(0, 3), (1, 3), (2, 3), (3, 2)] {
+        players[voter]
+            .push_choice(vec![Response::Player(players[target].clone())])
+            .await
+            .unwrap();
+    }
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(3)]);
+    assert!(reveal.winners.contains(&PlayerId(0)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn mortician() {
+    init_logging();
+
+    let seed = seed_for(
+        &mortician::Instruction::ALL,
+        &mortician::Instruction::ViewRight,
+    );
+
+    let players = players(4);
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Mortician::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles().with_seed(seed),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let mortician = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&mortician).unwrap().observations,
+        [
+            Observation::Instruction {
+                instruction: "ViewRight".to_string(),
+            },
+            Observation::Role {
+                target: ObservedTarget::Player(PlayerId(1)),
+                role: "Villager".to_string(),
+            },
+        ]
+    );
+
+    for (voter, target) in [(0, 1), (1, 0), (2, 1), (3, 1)] {
+        players[voter]
+            .push_choice(vec![Response::Player(players[target].clone())])
+            .await
+            .unwrap();
+    }
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(1)]);
+    assert!(reveal.winners.contains(&PlayerId(0)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn exposer() {
+    init_logging();
+
+    let seed = seed_for(&[1, 2, 3], &2);
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Num(2),
+            Response::Num(0),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Exposer::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles().with_seed(seed),
+    )
+    .await;
+
+    run_night(&mut game).await;
+    game.start_day().await.unwrap();
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(
+        snapshot
+            .center
+            .iter()
+            .map(|c| c.face_up)
+            .collect::<Vec<_>>(),
+        [true, false, true]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn body_snatcher() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(BodySnatcher::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let snatched = game.seats()[1].clone();
+    assert!(game.players_by_type()[&RoleType::Alien].contains(&snatched));
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(
+        snapshot
+            .players
+            .iter()
+            .map(|p| p.team.as_str())
+            .collect::<Vec<_>>(),
+        ["Villager", "Alien", "Villager"]
+    );
+}
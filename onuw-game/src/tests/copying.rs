@@ -0,0 +1,338 @@
+use super::{
+    init_logging, new_game, players, players_with_default_answers, run_night, testplayer::Response,
+};
+use crate::{
+    game::{
+        options::Options,
+        snapshot::{NightEvent, Observation, ObservedTarget},
+        PlayerId,
+    },
+    role::{
+        roles::{
+            copycat::Copycat, doppelganger::Doppelganger, hunter::Hunter, insomniac::Insomniac,
+            robber::Robber, seer::Seer, tanner::Tanner, villager::Villager, werewolf::Werewolf,
+            ROLES,
+        },
+        Role, MAX_COPY_DEPTH,
+    },
+};
+
+#[tokio::test(start_paused = true)]
+async fn doppelganger_copies_every_role() {
+    init_logging();
+
+    for (roledef, init) in ROLES.iter() {
+        if roledef.name == Doppelganger::new().id() {
+            continue;
+        }
+        let role = init.as_ref().unwrap()();
+        let id = role.id();
+
+        let players = players_with_default_answers(4);
+        players[0]
+            .push_choice(vec![Response::Player(players[1].clone())])
+            .await
+            .unwrap();
+
+        let mut game = new_game(
+            &players,
+            vec![
+                Box::new(Doppelganger::new()),
+                role,
+                Box::new(Villager::new()),
+                Box::new(Werewolf::new()),
+                Box::new(Hunter::new()),
+                Box::new(Tanner::new()),
+                Box::new(Villager::new()),
+            ],
+            Options::new().debug_with_set_roles().with_seed(0),
+        )
+        .await;
+
+        run_night(&mut game).await;
+        game.collect_votes().await.unwrap();
+        game.calc_dead_and_winners().await.unwrap();
+
+        // the doppelganger's card may have moved during the night
+        let snapshot = game.snapshot().await;
+        let copied = format!("{}({}", Doppelganger::new().id(), id);
+        assert!(
+            snapshot
+                .players
+                .iter()
+                .map(|p| &p.role)
+                .chain(snapshot.center.iter().map(|c| &c.role))
+                .any(|r| r.starts_with(&copied)),
+            "the doppelganger did not copy the {}",
+            id
+        );
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn doppelganger_seer_acts_now() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[3].clone()),
+            Response::Bool(true),
+            Response::Player(players[1].clone()),
+        ])
+        .await
+        .unwrap();
+    players[1]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Doppelganger::new()),
+            Box::new(Seer::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Villager::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let doppelganger = game.seats()[0].clone();
+    assert!(game
+        .player_view(&doppelganger)
+        .unwrap()
+        .observations
+        .contains(&Observation::Role {
+            target: ObservedTarget::Player(PlayerId(3)),
+            role: "Werewolf".to_string()
+        }));
+
+    // the copied seer looks during the doppelganger's turn, before the real seer wakes
+    let events = game.snapshot().await.night_events;
+    let position = |player, target| {
+        events.iter().position(|e| {
+            *e == NightEvent::Viewed {
+                player,
+                target: ObservedTarget::Player(target),
+            }
+        })
+    };
+    assert!(
+        position(PlayerId(0), PlayerId(3)).unwrap() < position(PlayerId(1), PlayerId(2)).unwrap()
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn doppelganger_insomniac_acts_later() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![Response::Player(players[1].clone())])
+        .await
+        .unwrap();
+    players[2]
+        .push_choice(vec![
+            Response::Player(players[0].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Doppelganger::new()),
+            Box::new(Insomniac::new()),
+            Box::new(Robber::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Villager::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    // the copied insomniac wakes at the end of the night and sees the robber's card
+    let doppelganger = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&doppelganger).unwrap().observations.last(),
+        Some(&Observation::Role {
+            target: ObservedTarget::Player(PlayerId(0)),
+            role: "Robber".to_string()
+        })
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn doppelganger_hunter() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![Response::Player(players[1].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Doppelganger::new()),
+            Box::new(Hunter::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Tanner::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    for (voter, target) in [(0, 3), (1, 0), (2, 0), (3, 0)] {
+        players[voter]
+            .push_choice(vec![Response::Player(players[target].clone())])
+            .await
+            .unwrap();
+    }
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    // the copied hunter is passive and takes the werewolf down with them
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(0), PlayerId(3)]);
+    assert_eq!(reveal.winners, [PlayerId(0), PlayerId(1), PlayerId(2)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn doppelganger_copies_copycat() {
+    init_logging();
+
+    // the copycat takes the seer from the center, then the doppelganger copies the copycat
+    let players = players_with_default_answers(4);
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Copycat::new()),
+            Box::new(Doppelganger::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Seer::new()),
+            Box::new(Tanner::new()),
+            Box::new(Villager::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(snapshot.players[0].role, "Copycat(Seer)");
+    assert_eq!(snapshot.players[0].copy_chain, ["Seer"]);
+    assert_eq!(snapshot.players[1].role, "Doppelganger(Copycat(Seer))");
+    assert_eq!(snapshot.players[1].copy_chain, ["Copycat", "Seer"]);
+
+    let doppelganger = game.players()[&game.seats()[1]].read().await.effective_id();
+    assert_eq!(doppelganger, Seer::new().id());
+
+    // the doppelganger looked at the copycat, then used the seer's action right away
+    let viewed = snapshot
+        .night_events
+        .iter()
+        .filter(|e| matches!(e, NightEvent::Viewed { .. }) && e.player() == PlayerId(1))
+        .count();
+    assert!(viewed >= 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn copy_depth_limit() {
+    init_logging();
+
+    // a chain of doppelgangers copying each other, starting from a copycat that took the seer
+    let players = players_with_default_answers(4);
+    for i in 2..4 {
+        players[i]
+            .push_choice(vec![Response::Player(players[i - 1].clone())])
+            .await
+            .unwrap();
+    }
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Copycat::new()),
+            Box::new(Doppelganger::new()),
+            Box::new(Doppelganger::new()),
+            Box::new(Doppelganger::new()),
+            Box::new(Seer::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(snapshot.players[2].copy_chain.len() + 1, MAX_COPY_DEPTH);
+    assert_eq!(
+        snapshot.players[2].role,
+        "Doppelganger(Doppelganger(Copycat(Seer)))"
+    );
+    // the last doppelganger only gets to look at the chain
+    assert_eq!(snapshot.players[3].role, "Doppelganger()");
+    assert!(snapshot.players[3].copy_chain.is_empty());
+    assert_eq!(
+        game.player_view(&game.seats()[3]).unwrap().observations,
+        [Observation::Role {
+            target: ObservedTarget::Player(PlayerId(2)),
+            role: "Seer".to_string()
+        }]
+    );
+
+    // a copycat finding the chain in the center is turned away as well
+    let chain = game.players()[&game.seats()[2]].read().await.clone();
+    let players = players_with_default_answers(3);
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Copycat::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            chain,
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(snapshot.players[0].role, "Copycat()");
+    assert!(snapshot.players[0].copy_chain.is_empty());
+    assert_eq!(
+        game.player_view(&game.seats()[0]).unwrap().observations,
+        [Observation::Role {
+            target: ObservedTarget::Center(0),
+            role: "Seer".to_string()
+        }]
+    );
+}
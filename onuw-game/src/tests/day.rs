@@ -0,0 +1,448 @@
+use super::{init_logging, new_game, players, run_night, testplayer::Response};
+use crate::{
+    game::{
+        artifact::Artifact,
+        options::Options,
+        snapshot::{Observation, ObservedTarget},
+        PlayerId,
+    },
+    playerinterface::message::{ClaimType, Message, MessageType},
+    role::{
+        roles::{
+            apprenticetanner::ApprenticeTanner, curator::Curator, cursed::Cursed, hunter::Hunter,
+            minion::Minion, prince::Prince, revealer::Revealer, tanner::Tanner,
+            troublemaker::Troublemaker, villageidiot::VillageIdiot, villager::Villager,
+            werewolf::Werewolf,
+        },
+        roletype::RoleType,
+        Role,
+    },
+};
+
+#[tokio::test(start_paused = true)]
+async fn village_idiot() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![Response::Num(1)])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(VillageIdiot::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Tanner::new()),
+            Box::new(Hunter::new()),
+            Box::new(Villager::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(
+        snapshot
+            .players
+            .iter()
+            .map(|p| p.role.as_str())
+            .collect::<Vec<_>>(),
+        ["VillageIdiot", "Tanner", "Villager", "Werewolf"]
+    );
+
+    let new_werewolf = game.seats()[3].clone();
+    assert_eq!(
+        game.players_by_type()[&RoleType::Werewolf]
+            .iter()
+            .collect::<Vec<_>>(),
+        [&new_werewolf]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn village_idiot_after_troublemaker() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![Response::Num(1)])
+        .await
+        .unwrap();
+    players[1]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Player(players[0].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(VillageIdiot::new()),
+            Box::new(Troublemaker::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(
+        snapshot
+            .players
+            .iter()
+            .map(|p| p.role.as_str())
+            .collect::<Vec<_>>(),
+        ["Villager", "Werewolf", "Troublemaker", "VillageIdiot"]
+    );
+
+    let new_werewolf = game.seats()[1].clone();
+    assert_eq!(
+        game.players_by_type()[&RoleType::Werewolf]
+            .iter()
+            .collect::<Vec<_>>(),
+        [&new_werewolf]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn revealer() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![Response::Player(players[1].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Revealer::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let face_up = Observation::Role {
+        target: ObservedTarget::Player(PlayerId(1)),
+        role: "Villager".to_string(),
+    };
+
+    let werewolf = game.seats()[2].clone();
+    assert!(!game
+        .player_view(&werewolf)
+        .unwrap()
+        .observations
+        .contains(&face_up));
+
+    game.start_day().await.unwrap();
+
+    assert!(game
+        .player_view(&werewolf)
+        .unwrap()
+        .observations
+        .contains(&face_up));
+    assert_eq!(
+        game.snapshot()
+            .await
+            .players
+            .iter()
+            .map(|p| p.face_up)
+            .collect::<Vec<_>>(),
+        [false, true, false]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn revealer_after_troublemaker() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![Response::Player(players[2].clone())])
+        .await
+        .unwrap();
+    players[1]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Player(players[0].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Revealer::new()),
+            Box::new(Troublemaker::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let revealer = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&revealer).unwrap().observations,
+        [Observation::Role {
+            target: ObservedTarget::Player(PlayerId(2)),
+            role: "Revealer".to_string()
+        }]
+    );
+    assert_eq!(
+        game.snapshot()
+            .await
+            .players
+            .iter()
+            .map(|p| p.face_up)
+            .collect::<Vec<_>>(),
+        [false, false, true]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn curator() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Curator::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    game.start_day().await.unwrap();
+
+    let holder = game.seats()[1].clone();
+    let artifact = game.artifacts()[&holder];
+    assert_eq!(
+        game.player_view(&holder).unwrap().observations.last(),
+        Some(&Observation::Artifact {
+            target: ObservedTarget::Player(PlayerId(1)),
+            artifact: artifact.to_string()
+        })
+    );
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(snapshot.players[1].artifact, Some(artifact.to_string()));
+    assert_eq!(
+        snapshot.players[1].team,
+        artifact
+            .role()
+            .map(|r| r.team())
+            .unwrap_or_else(|| "Villager".to_string())
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn artifacts_change_team_and_powers() {
+    init_logging();
+
+    let players = players(3);
+    for p in players.iter() {
+        p.push_choice(vec![Response::Player(players[2].clone())])
+            .await
+            .unwrap();
+    }
+    players[2]
+        .push_choice(vec![Response::Player(players[1].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        (0..6).map(|_| Box::new(Villager::new()) as _).collect(),
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    let clawed = game.seats()[0].clone();
+    let muted = game.seats()[1].clone();
+    game.place_artifact(&Villager::new(), &clawed, Artifact::ClawOfTheWerewolf);
+    game.place_artifact(&Villager::new(), &muted, Artifact::MaskOfMuting);
+
+    assert!(game.players_by_type()[&RoleType::Werewolf].contains(&clawed));
+
+    game.send_message_to_players(Message {
+        mtype: MessageType::Claim(ClaimType::IsRole("Villager".to_string())),
+        sender: muted.clone(),
+    })
+    .await;
+    assert!(game.player_view(&clawed).unwrap().messages.is_empty());
+
+    game.start_day().await.unwrap();
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(2)]);
+    assert_eq!(reveal.winners, [PlayerId(0)]);
+    assert_eq!(reveal.winning_teams, ["Werewolf"]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn prince_and_cursed() {
+    init_logging();
+
+    let players = players(4);
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Prince::new()),
+            Box::new(Cursed::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    for (voter, target) in [(0, 1), (1, 0), (2, 0), (3, 0)] {
+        players[voter]
+            .push_choice(vec![Response::Player(players[target].clone())])
+            .await
+            .unwrap();
+    }
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    // the prince survives, so the cursed dies as the werewolf they became
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(1)]);
+    assert_eq!(reveal.winners, [PlayerId(0), PlayerId(2)]);
+    assert_eq!(reveal.players[1].team, "Werewolf");
+}
+
+#[tokio::test(start_paused = true)]
+async fn apprentice_tanner() {
+    init_logging();
+
+    let players = players(3);
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(ApprenticeTanner::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let apprentice = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&apprentice).unwrap().observations,
+        [Observation::Role {
+            target: ObservedTarget::Player(PlayerId(1)),
+            role: "Tanner".to_string(),
+        }]
+    );
+    // seeing the tanner is not looking at their card
+    assert!(game.snapshot().await.night_events.is_empty());
+
+    for (voter, target) in [(0, 1), (1, 2), (2, 1)] {
+        players[voter]
+            .push_choice(vec![Response::Player(players[target].clone())])
+            .await
+            .unwrap();
+    }
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(1)]);
+    assert_eq!(reveal.winners, [PlayerId(0), PlayerId(1)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn curator_on_own_card() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[0].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Curator::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().with_seed(2).debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let curator = game.seats()[0].clone();
+    let artifact = game.artifacts()[&curator];
+    let role_type = artifact.role().unwrap().role_type();
+    assert_eq!(artifact, Artifact::ClawOfTheWerewolf);
+    assert!(game.players_by_type()[&role_type].contains(&curator));
+    assert!(!game.players_by_type()[&RoleType::Villager].contains(&curator));
+}
@@ -1,8 +1,23 @@
+mod aliens;
+mod copying;
+mod day;
+mod packs;
+mod protection;
+mod scripting;
+mod seating;
+mod seers;
 mod testplayer;
+mod vampires;
+mod werewolves;
 
 use self::testplayer::TestPlayerInterface;
 use crate::{
-    game::{options::Options, ONUWGame},
+    game::{
+        gamerror::GameError,
+        options::{NightTurnLength, Options},
+        snapshot::{Observation, ObservedTarget},
+        ONUWGame, PlayerId,
+    },
     playerinterface::{
         message::{ClaimType, Message, MessageType, QuestionType},
         PlayerInterface,
@@ -13,11 +28,18 @@ use crate::{
             mason::Mason, minion::Minion, robber::Robber, seer::Seer, tanner::Tanner,
             troublemaker::Troublemaker, villager::Villager, werewolf::Werewolf,
         },
+        roletype::RoleType,
         Role,
     },
     tests::testplayer::Response,
 };
-use std::{collections::BTreeMap, future::ready, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 use testplayer::TestPlayer;
 use tracing::{error, info, warn};
 use tracing_subscriber::{filter::LevelFilter, fmt, fmt::format, fmt::format::FmtSpan, EnvFilter};
@@ -47,7 +69,62 @@ fn players(num: i32) -> Vec<Arc<TestPlayerInterface>> {
         .collect()
 }
 
-#[tokio::test]
+fn players_with_default_answers(num: i32) -> Vec<Arc<TestPlayerInterface>> {
+    (0..num)
+        .map(|i| Arc::new(TestPlayer::init_with_default_answers(format!("{}", i))))
+        .collect()
+}
+
+async fn new_game(
+    players: &[Arc<TestPlayerInterface>],
+    roles: Vec<Box<dyn Role>>,
+    options: Options,
+) -> ONUWGame {
+    ONUWGame::new(
+        players
+            .iter()
+            .cloned()
+            .map(|v| v as Arc<dyn PlayerInterface>)
+            .collect(),
+        roles,
+        options,
+    )
+    .await
+    .unwrap()
+}
+
+async fn run_night(game: &mut ONUWGame) {
+    while game.peek_next_night_action().is_some() {
+        game.perform_next_night_action().await.unwrap();
+    }
+}
+
+/// A directory of its own for a test's files, removed again when it is dropped.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("onuw-game-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        Self(dir)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        std::fs::remove_dir_all(&self.0).unwrap_or_default();
+    }
+}
+
+#[tokio::test(start_paused = true)]
 async fn test() {
     init_logging();
 
@@ -91,11 +168,8 @@ async fn test() {
         p.push_choice(c).await.unwrap();
     }
 
-    let mut game = ONUWGame::new(
-        players
-            .into_iter()
-            .map(|v| v as Arc<dyn PlayerInterface>)
-            .collect(),
+    let mut game = new_game(
+        &players,
         vec![
             Box::new(Doppelganger::new()),
             Box::new(Werewolf::new()),
@@ -114,15 +188,14 @@ async fn test() {
         ],
         Options::new().debug_with_set_roles(),
     )
-    .await
-    .unwrap();
+    .await;
 
     game.send_handshake().await;
     game.show_all_roles().await;
 
     while let Some(priority) = game.peek_next_night_action() {
         warn!("doing night action {:#>}", priority);
-        game.perform_next_night_action(|| ready(())).await.unwrap();
+        game.perform_next_night_action().await.unwrap();
     }
 
     info!(
@@ -137,17 +210,14 @@ async fn test() {
     game.calc_dead_and_winners().await.unwrap();
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn message_passing() {
     init_logging();
 
     let players = players(5);
 
-    let game = ONUWGame::new(
-        players
-            .into_iter()
-            .map(|v| v as Arc<dyn PlayerInterface>)
-            .collect(),
+    let mut game = new_game(
+        &players,
         vec![
             Box::new(Villager::new()),
             Box::new(Villager::new()),
@@ -160,8 +230,7 @@ async fn message_passing() {
         ],
         Options::new().debug_with_set_roles(),
     )
-    .await
-    .unwrap();
+    .await;
 
     let mut players: Vec<_> = game.players().iter().map(|(p, _)| p).collect();
     players.sort_by(|a, b| a.name().cmp(b.name()));
@@ -185,3 +254,478 @@ async fn message_passing() {
     warn!("sending message 2");
     game.send_message_to_players(example_answer).await;
 }
+
+#[tokio::test(start_paused = true)]
+async fn center_count() {
+    init_logging();
+
+    let result = ONUWGame::new(
+        players(3)
+            .into_iter()
+            .map(|v| v as Arc<dyn PlayerInterface>)
+            .collect(),
+        (0..5).map(|_| Box::new(Villager::new()) as _).collect(),
+        Options::new(),
+    )
+    .await;
+    assert!(matches!(
+        result,
+        Err(GameError::InvalidRoleCount {
+            roles: 5,
+            players: 3,
+            center: 3
+        })
+    ));
+
+    let result = ONUWGame::new(
+        players(3)
+            .into_iter()
+            .map(|v| v as Arc<dyn PlayerInterface>)
+            .collect(),
+        (0..9).map(|_| Box::new(Villager::new()) as _).collect(),
+        Options::new().with_center_count(6),
+    )
+    .await;
+    assert!(matches!(
+        result,
+        Err(GameError::InvalidCenterCount { center: 6 })
+    ));
+
+    let players = players(2);
+    players[0]
+        .push_choice(vec![Response::Num(0)])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Drunk::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().with_center_count(1).debug_with_set_roles(),
+    )
+    .await;
+
+    assert_eq!(game.centerroles().len(), 1);
+
+    run_night(&mut game).await;
+
+    let drunk = game.seats()[0].clone();
+    assert_eq!(game.players()[&drunk].read().await.id(), "Werewolf");
+    assert_eq!(game.centerroles()[0].read().await.id(), "Drunk");
+    assert!(game.players_by_type()[&RoleType::Werewolf].contains(&drunk));
+}
+
+#[tokio::test(start_paused = true)]
+async fn snapshots_and_views() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Num(1),
+            Response::Num(0),
+            Response::Bool(false),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Seer::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+            Box::new(Hunter::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    game.show_all_roles().await;
+
+    run_night(&mut game).await;
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(
+        snapshot
+            .players
+            .iter()
+            .map(|p| (p.name.as_str(), p.role.as_str()))
+            .collect::<Vec<_>>(),
+        [("0", "Seer"), ("1", "Villager"), ("2", "Werewolf")]
+    );
+    assert_eq!(snapshot.center[1].role, "Minion");
+    assert!(snapshot.pending_night_actions.is_empty());
+    assert!(snapshot.votes.is_none());
+
+    let seer = game.seats()[0].clone();
+    let seer_view = game.player_view(&seer).unwrap();
+    assert_eq!(seer_view.dealt_role, "Seer");
+    assert!(seer_view.observations.contains(&Observation::Role {
+        target: ObservedTarget::Center(1),
+        role: "Minion".to_string()
+    }));
+
+    let villager = game.seats()[1].clone();
+    let villager_view = game.player_view(&villager).unwrap();
+    assert_eq!(
+        villager_view.observations,
+        [Observation::Role {
+            target: ObservedTarget::Player(PlayerId(1)),
+            role: "Villager".to_string()
+        }]
+    );
+}
+
+/// Two games with the same seed and the same answers play out exactly alike, down to the order
+/// in which cards are viewed.
+#[tokio::test(start_paused = true)]
+async fn reproducible_games() {
+    init_logging();
+
+    let mut snapshots = Vec::new();
+    for _ in 0..2 {
+        let players = players_with_default_answers(5);
+        let mut game = new_game(
+            &players,
+            vec![
+                Box::new(Werewolf::new()),
+                Box::new(Werewolf::new()),
+                Box::new(Mason::new()),
+                Box::new(Mason::new()),
+                Box::new(Seer::new()),
+                Box::new(Robber::new()),
+                Box::new(Troublemaker::new()),
+                Box::new(Villager::new()),
+            ],
+            Options::new().with_seed(7),
+        )
+        .await;
+
+        game.send_handshake().await;
+        game.show_all_roles().await;
+
+        run_night(&mut game).await;
+
+        game.collect_votes().await.unwrap();
+        game.calc_dead_and_winners().await.unwrap();
+
+        snapshots.push(game.snapshot().await);
+    }
+
+    assert_eq!(snapshots[0], snapshots[1]);
+    assert_eq!(
+        snapshots[0].night_events[..5]
+            .iter()
+            .map(|e| e.player())
+            .collect::<Vec<_>>(),
+        (0..5).map(PlayerId).collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn night_turn_pacing() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Num(1),
+            Response::Num(0),
+            Response::Bool(false),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Seer::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Robber::new()),
+            Box::new(Minion::new()),
+            Box::new(Hunter::new()),
+        ],
+        Options::new()
+            .with_night_turn_length(NightTurnLength::Fixed(Duration::from_secs(10)))
+            .with_role_night_turn_length("Robber", NightTurnLength::Fixed(Duration::from_secs(20)))
+            .debug_with_set_roles(),
+    )
+    .await;
+
+    let mut lengths = Vec::new();
+    while let Some(priority) = game.peek_next_night_action().cloned() {
+        let started = tokio::time::Instant::now();
+        game.perform_next_night_action().await.unwrap();
+        lengths.push((priority, started.elapsed().as_secs()));
+    }
+
+    assert_eq!(
+        lengths,
+        [
+            ("2".to_string(), 10),
+            ("3".to_string(), 10),
+            ("5".to_string(), 10),
+            ("6".to_string(), 20),
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn night_turn_time_out() {
+    init_logging();
+
+    let players = players(3);
+    players[0].push_choice(vec![Response::Stall]).await.unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Seer::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Robber::new()),
+            Box::new(Minion::new()),
+            Box::new(Hunter::new()),
+        ],
+        Options::new()
+            .with_night_turn_length(NightTurnLength::Fixed(Duration::from_secs(10)))
+            .debug_with_set_roles(),
+    )
+    .await;
+
+    let mut lengths = Vec::new();
+    while let Some(priority) = game.peek_next_night_action().cloned() {
+        let started = tokio::time::Instant::now();
+        game.perform_next_night_action().await.unwrap();
+        lengths.push((priority, started.elapsed().as_secs()));
+    }
+
+    // the seer never makes up their mind and is cut off when their turn is over
+    assert_eq!(lengths[2], ("5".to_string(), 10));
+    let seer = game.seats()[0].clone();
+    assert!(game.player_view(&seer).unwrap().observations.is_empty());
+    game.start_day().await.unwrap();
+}
+
+/// Random turn lengths come from the game's seed, so a replay keeps the night's pacing.
+#[tokio::test(start_paused = true)]
+async fn seeded_night_turn_lengths() {
+    init_logging();
+
+    let mut runs = Vec::new();
+    for _ in 0..2 {
+        let players = players_with_default_answers(3);
+        let mut game = new_game(
+            &players,
+            vec![
+                Box::new(Seer::new()),
+                Box::new(Villager::new()),
+                Box::new(Werewolf::new()),
+                Box::new(Robber::new()),
+                Box::new(Minion::new()),
+                Box::new(Hunter::new()),
+            ],
+            Options::new()
+                .with_night_turn_length(NightTurnLength::Random {
+                    min: Duration::from_secs(5),
+                    max: Duration::from_secs(15),
+                })
+                .with_seed(11),
+        )
+        .await;
+
+        let mut lengths = Vec::new();
+        while game.peek_next_night_action().is_some() {
+            let started = tokio::time::Instant::now();
+            game.perform_next_night_action().await.unwrap();
+            lengths.push(started.elapsed());
+        }
+        runs.push(lengths);
+    }
+
+    assert_eq!(runs[0], runs[1]);
+}
+
+/// Every player holding a role gets a whole turn, so one slow player doesn't cut off the rest.
+#[tokio::test(start_paused = true)]
+async fn night_turn_per_player() {
+    init_logging();
+
+    let players = players(3);
+    players[0].push_choice(vec![Response::Stall]).await.unwrap();
+    players[1]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Seer::new()),
+            Box::new(Seer::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Robber::new()),
+            Box::new(Minion::new()),
+            Box::new(Hunter::new()),
+        ],
+        Options::new()
+            .with_night_turn_length(NightTurnLength::Fixed(Duration::from_secs(10)))
+            .debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let second_seer = game.seats()[1].clone();
+    assert_eq!(
+        game.player_view(&second_seer).unwrap().observations,
+        [Observation::Role {
+            target: ObservedTarget::Player(PlayerId(2)),
+            role: "Werewolf".to_string()
+        }]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn reveal() {
+    init_logging();
+
+    let players = players(3);
+    let tanner = players[2].clone() as Arc<dyn PlayerInterface>;
+    players[0]
+        .push_choice(vec![Response::Player(tanner.clone())])
+        .await
+        .unwrap();
+    players[1]
+        .push_choice(vec![Response::Player(tanner.clone())])
+        .await
+        .unwrap();
+    players[2]
+        .push_choice(vec![Response::Player(players[0].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Minion::new()),
+            Box::new(Hunter::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    game.collect_votes().await.unwrap();
+    assert!(game.reveal().await.is_none());
+
+    game.calc_dead_and_winners().await.unwrap();
+
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(2)]);
+    assert_eq!(reveal.winners, [PlayerId(2)]);
+    assert_eq!(reveal.winning_teams, ["Tanner"]);
+    assert_eq!(
+        reveal
+            .players
+            .iter()
+            .map(|p| (p.name.as_str(), p.dealt_role.as_str(), p.team.as_str()))
+            .collect::<Vec<_>>(),
+        [
+            ("0", "Villager", "Villager"),
+            ("1", "Villager", "Villager"),
+            ("2", "Tanner", "Tanner"),
+        ]
+    );
+    assert_eq!(
+        reveal
+            .center
+            .iter()
+            .map(|c| c.role.as_str())
+            .collect::<Vec<_>>(),
+        ["Werewolf", "Minion", "Hunter"]
+    );
+}
+
+/// Players are told apart by the id the game gives them, so a lobby can seat players with the
+/// same name.
+#[tokio::test(start_paused = true)]
+async fn duplicate_names() {
+    init_logging();
+
+    let players: Vec<_> = (0..3)
+        .map(|_| Arc::new(TestPlayer::init("Sam".to_string())))
+        .collect();
+    let tanner = players[2].clone() as Arc<dyn PlayerInterface>;
+    for player in &players[..2] {
+        player
+            .push_choice(vec![Response::Player(tanner.clone())])
+            .await
+            .unwrap();
+    }
+    players[2]
+        .push_choice(vec![Response::Player(players[0].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Minion::new()),
+            Box::new(Hunter::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    assert_eq!(game.players().len(), 3);
+
+    run_night(&mut game).await;
+
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    let tanner = game.seats()[2].clone();
+    assert_eq!(game.votes().as_ref().unwrap().len(), 3);
+    assert_eq!(
+        game.dead().as_ref().unwrap().iter().collect::<Vec<_>>(),
+        [&tanner]
+    );
+    assert_eq!(
+        game.winners().as_ref().unwrap().iter().collect::<Vec<_>>(),
+        [&tanner]
+    );
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(
+        snapshot
+            .players
+            .iter()
+            .map(|p| (p.id, p.name.as_str(), p.dealt_role.as_str()))
+            .collect::<Vec<_>>(),
+        [
+            (PlayerId(0), "Sam", "Villager"),
+            (PlayerId(1), "Sam", "Villager"),
+            (PlayerId(2), "Sam", "Tanner"),
+        ]
+    );
+}
@@ -0,0 +1,138 @@
+use super::TempDir;
+use crate::role::{
+    pack::{PackError, RolePack, DEFAULT_PACK},
+    roles::{apply_pack, mason::Mason, villager::Villager, werewolf::Werewolf, ROLES},
+    Role,
+};
+use itertools::Itertools;
+
+const EXPANSIONS: &[&str] = &[
+    "One Night Ultimate Werewolf",
+    "Daybreak",
+    "Vampire",
+    "Alien",
+    "Bonus Pack 1",
+    "Bonus Pack 2",
+];
+
+#[test]
+fn default_pack() {
+    let built_in: Vec<_> = ROLES
+        .iter()
+        .filter(|(def, _)| def.expansion != "Scripted")
+        .collect();
+    assert_eq!(DEFAULT_PACK.roles.len(), built_in.len());
+
+    for (def, init) in built_in {
+        assert_eq!(DEFAULT_PACK.role(&def.name), Some(def));
+
+        // the pack agrees with what the role does in a game
+        let role = init.as_ref().unwrap()();
+        assert_eq!(def.team, role.team(), "{}", def.name);
+        assert_eq!(
+            def.wake_order.as_ref(),
+            role.priorities().into_iter().sorted().next(),
+            "{}",
+            def.name
+        );
+
+        // and describes it the way the app expects
+        assert_eq!(def.display_name.replace(' ', ""), def.name);
+        assert_eq!(def.image, image_name(&def.name));
+        assert!(!def.description.is_empty(), "{}", def.name);
+        assert!(EXPANSIONS.contains(&def.expansion.as_str()), "{}", def.name);
+
+        let amounts = match def.name.as_str() {
+            "Mason" => (Mason::min_amt(), Mason::max_amt()),
+            "Villager" => (Villager::min_amt(), Villager::max_amt()),
+            "Werewolf" => (Werewolf::min_amt(), Werewolf::max_amt()),
+            _ => (1, 1),
+        };
+        assert_eq!((def.min_amt, def.max_amt), amounts, "{}", def.name);
+    }
+    assert_eq!(
+        DEFAULT_PACK.role("AlphaWolf").unwrap().display_name,
+        "Alpha Wolf"
+    );
+    assert!(DEFAULT_PACK
+        .role("ApprenticeAssassin")
+        .unwrap()
+        .description
+        .ends_with("Wins if the Assassin dies."));
+}
+
+#[test]
+fn load_pack() {
+    let dir = TempDir::new("packs");
+
+    let path = dir.join("house-rules.json");
+    std::fs::write(
+        &path,
+        r#"{
+            "name": "House Rules",
+            "roles": [
+                {
+                    "name": "Villager",
+                    "description": "Up to five villagers.",
+                    "min_amt": 1,
+                    "max_amt": 5,
+                    "expansion": "House Rules"
+                },
+                {
+                    "name": "Lookout",
+                    "image": "seer",
+                    "min_amt": 1,
+                    "max_amt": 1
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let pack = RolePack::load(&path).unwrap();
+    let villager = pack.role("Villager").unwrap();
+    assert_eq!(villager.max_amt, 5);
+    assert_eq!(villager.wake_order, None);
+
+    // the pack replaces what it gives and leaves the rest
+    let roles = apply_pack(ROLES.clone(), &pack);
+    assert_eq!(roles.len(), ROLES.len());
+    let villager = roles.keys().find(|def| def.name == "Villager").unwrap();
+    assert_eq!(villager.description, "Up to five villagers.");
+    assert_eq!(villager.max_amt, 5);
+    assert_eq!(villager.display_name, "Villager");
+    assert_eq!(villager.team, "Villager");
+    assert_eq!(villager.image, "villager");
+    let lookout = roles.keys().find(|def| def.name == "Lookout").unwrap();
+    assert_eq!(lookout.image, "seer");
+    assert_eq!(lookout.expansion, "Scripted");
+    assert_eq!(lookout.wake_order.as_deref(), Some("5E"));
+
+    let path = dir.join("unknown.json");
+    std::fs::write(
+        &path,
+        r#"{"name": "Typos", "roles": [{"name": "Vilager", "min_amt": 1, "max_amt": 1}]}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        RolePack::load(&path),
+        Err(PackError::UnknownRole { name }) if name == "Vilager"
+    ));
+
+    assert!(matches!(
+        RolePack::load(dir.join("missing.json")),
+        Err(PackError::Io(_))
+    ));
+}
+
+/// Turns a role name like `AlphaWolf` into the stem of its image file, `alpha_wolf`.
+fn image_name(name: &str) -> String {
+    name.chars()
+        .enumerate()
+        .flat_map(|(i, c)| {
+            (c.is_uppercase() && i != 0)
+                .then_some('_')
+                .into_iter()
+                .chain(c.to_lowercase())
+        })
+        .collect()
+}
@@ -0,0 +1,141 @@
+use super::{init_logging, new_game, players, run_night, testplayer::Response};
+use crate::{
+    game::{options::Options, PlayerId},
+    role::{
+        roles::{
+            bodyguard::Bodyguard, drunk::Drunk, hunter::Hunter, minion::Minion, robber::Robber,
+            sentinel::Sentinel, tanner::Tanner, villager::Villager, werewolf::Werewolf,
+        },
+        Role,
+    },
+};
+
+#[tokio::test(start_paused = true)]
+async fn sentinel_shield_blocks_swaps() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+    // the shielded villager is not offered to the robber
+    players[1]
+        .push_choice(vec![
+            Response::Player(players[3].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Sentinel::new()),
+            Box::new(Robber::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(
+        snapshot
+            .players
+            .iter()
+            .map(|p| (p.role.as_str(), p.shielded))
+            .collect::<Vec<_>>(),
+        [
+            ("Sentinel", false),
+            ("Werewolf", false),
+            ("Villager", true),
+            ("Robber", false),
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn sentinel_shield_keeps_drunk_in_place() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Sentinel::new()),
+            Box::new(Drunk::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let drunk = game.seats()[1].clone();
+    assert_eq!(game.players()[&drunk].read().await.id(), "Drunk");
+    assert_eq!(game.centerroles()[0].read().await.id(), "Werewolf");
+}
+
+#[tokio::test(start_paused = true)]
+async fn bodyguard() {
+    init_logging();
+
+    let players = players(4);
+    for i in [0, 1, 3] {
+        players[i]
+            .push_choice(vec![Response::Player(players[2].clone())])
+            .await
+            .unwrap();
+    }
+    players[2]
+        .push_choice(vec![Response::Player(players[1].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Bodyguard::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    // the werewolf got the most votes, but the bodyguard protected them
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(1)]);
+    assert_eq!(reveal.winners, [PlayerId(2)]);
+}
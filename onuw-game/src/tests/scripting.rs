@@ -0,0 +1,167 @@
+use super::{init_logging, new_game, players, run_night, testplayer::Response, TempDir};
+use crate::{
+    game::{
+        options::Options,
+        snapshot::{Observation, ObservedTarget},
+        PlayerId,
+    },
+    role::{
+        roles::{
+            scripted_pair, seer::Seer, tanner::Tanner, villager::Villager, werewolf::Werewolf,
+            ROLES, ROLES_STRINGS,
+        },
+        script::{load_scripts, Script, ScriptError, Step, Target, SCRIPTS},
+        Role,
+    },
+};
+
+#[test]
+fn bundled_scripts() {
+    for source in SCRIPTS {
+        let script = Script::parse(source).unwrap();
+        assert!(ROLES_STRINGS.contains_key(&script.name));
+    }
+    // a script cannot take the name of a built-in role
+    assert_eq!(ROLES.len(), ROLES_STRINGS.len());
+    // scripts show the card of their type
+    assert_eq!(ROLES_STRINGS["Lookout"].image, "villager");
+
+    let lookout = Script::parse(SCRIPTS[0]).unwrap();
+    assert_eq!(lookout.priority.as_deref(), Some("5E"));
+    assert_eq!(lookout.steps, [Step::Ask, Step::View(Target::Neighbour)]);
+}
+
+#[test]
+fn script_errors() {
+    assert_eq!(
+        Script::parse("type Villager\nwins villager"),
+        Err(ScriptError::MissingName)
+    );
+    assert_eq!(
+        Script::parse("role Thief\nwins villager\nview player"),
+        Err(ScriptError::MissingPriority {
+            role: "Thief".to_string()
+        })
+    );
+    assert_eq!(
+        Script::parse("role Thief\n# steals\nwins villager\nnight 6C\nsteal player"),
+        Err(ScriptError::Syntax {
+            line: 5,
+            message: "unknown statement steal".to_string()
+        })
+    );
+    assert_eq!(
+        Script::parse("role Thief\nwins villager\nnight 6C\nswap center 2"),
+        Err(ScriptError::Syntax {
+            line: 4,
+            message: "only one center card can be swapped".to_string()
+        })
+    );
+}
+
+#[test]
+fn name_clashes() {
+    let seer = Script::parse("role Seer\nwins villager").unwrap();
+    assert_eq!(
+        scripted_pair(seer, &ROLES).err(),
+        Some(ScriptError::NameTaken {
+            role: "Seer".to_string()
+        })
+    );
+
+    let lookout = Script::parse(SCRIPTS[0]).unwrap();
+    assert!(scripted_pair(lookout, &ROLES).is_err());
+
+    let guard = Script::parse("role Guard\nwins villager").unwrap();
+    let (def, init) = scripted_pair(guard, &ROLES).unwrap();
+    assert_eq!(def.name, "Guard");
+    assert_eq!(init.unwrap()().id(), "Guard");
+}
+
+#[test]
+fn script_directory() {
+    let dir = TempDir::new("scripts");
+    std::fs::write(dir.join("b-guard.role"), "role Guard\nwins villager").unwrap();
+    std::fs::write(
+        dir.join("a-thief.role"),
+        "role Thief\nnight 6C\nswap player",
+    )
+    .unwrap();
+    std::fs::write(dir.join("notes.txt"), "not a script").unwrap();
+
+    let scripts = load_scripts(&*dir).unwrap();
+    assert_eq!(
+        scripts
+            .iter()
+            .map(|(path, _)| path.file_name().unwrap().to_str().unwrap())
+            .collect::<Vec<_>>(),
+        ["a-thief.role", "b-guard.role"]
+    );
+    assert_eq!(
+        scripts[0].1,
+        Err(ScriptError::MissingWinCondition {
+            role: "Thief".to_string()
+        })
+    );
+    assert_eq!(scripts[1].1.as_ref().unwrap().name, "Guard");
+
+    assert!(load_scripts(dir.join("missing")).is_err());
+}
+
+#[tokio::test(start_paused = true)]
+async fn scripted_roles() {
+    init_logging();
+
+    let scripted = |name: &str| ROLES[&ROLES_STRINGS[name]].as_ref().unwrap()();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+    players[1]
+        .push_choice(vec![Response::Num(0), Response::Bool(true)])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            scripted("Lookout"),
+            scripted("Smuggler"),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Seer::new()),
+            Box::new(Tanner::new()),
+            Box::new(Villager::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    // the smuggler saw the werewolf and traded for the seer in the center
+    let smuggler = game.seats()[1].clone();
+    assert_eq!(
+        game.player_view(&smuggler).unwrap().observations,
+        [Observation::RoleType {
+            target: ObservedTarget::Player(PlayerId(3)),
+            role_type: "Werewolf".to_string()
+        }]
+    );
+    assert_eq!(game.centerroles()[0].read().await.id(), "Smuggler");
+
+    // the lookout then looked at their neighbour's new card
+    let lookout = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&lookout).unwrap().observations,
+        [Observation::Role {
+            target: ObservedTarget::Player(PlayerId(1)),
+            role: Seer::new().id()
+        }]
+    );
+}
@@ -0,0 +1,92 @@
+use super::{init_logging, new_game, players, testplayer::TestPlayerInterface};
+use crate::{
+    game::{options::Options, ONUWGame, PlayerId},
+    role::{
+        roles::{villager::Villager, werewolf::Werewolf},
+        Role,
+    },
+};
+use std::sync::Arc;
+
+async fn villager_game(players: &[Arc<TestPlayerInterface>], options: Options) -> ONUWGame {
+    let mut roles: Vec<Box<dyn Role>> = vec![Box::new(Werewolf::new())];
+    while roles.len() < players.len() + options.center_count() {
+        roles.push(Box::new(Villager::new()));
+    }
+
+    new_game(players, roles, options).await
+}
+
+#[tokio::test(start_paused = true)]
+async fn seats_follow_given_order() {
+    init_logging();
+
+    let players = players(4);
+    let game = villager_game(&players, Options::new()).await;
+    let seats = game.seats();
+
+    assert_eq!(
+        seats.iter().map(|p| p.name()).collect::<Vec<_>>(),
+        ["0", "1", "2", "3"]
+    );
+    assert_eq!(game.seat_of(&seats[2]), Some(2));
+    assert_eq!(game.right_of(&seats[0]), Some(&seats[1]));
+    assert_eq!(game.left_of(&seats[0]), Some(&seats[3]));
+    assert_eq!(game.seat_at_offset(&seats[3], 2), Some(&seats[1]));
+    assert_eq!(game.neighbours(&seats[1]), vec![&seats[0], &seats[2]]);
+
+    let snapshot = game.snapshot().await;
+    for player in snapshot.players {
+        assert_eq!(seats[player.seat].id(), player.id);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn small_tables() {
+    init_logging();
+
+    let players = players(2);
+    let game = villager_game(&players, Options::new()).await;
+    let seats = game.seats();
+
+    assert_eq!(game.left_of(&seats[0]), game.right_of(&seats[0]));
+    assert_eq!(game.neighbours(&seats[0]), vec![&seats[1]]);
+
+    let game = villager_game(&players[..1], Options::new()).await;
+    let seats = game.seats();
+    assert_eq!(game.left_of(&seats[0]), Some(&seats[0]));
+    assert!(game.neighbours(&seats[0]).is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn random_seating_replays() {
+    init_logging();
+
+    let players = players(6);
+
+    let mut tables = Vec::new();
+    for seed in 0..8 {
+        let seated = villager_game(
+            &players,
+            Options::new().with_seed(seed).with_random_seating(),
+        )
+        .await;
+        let replayed = villager_game(
+            &players,
+            Options::new().with_seed(seed).with_random_seating(),
+        )
+        .await;
+        let unseated = villager_game(&players, Options::new().with_seed(seed)).await;
+
+        assert_eq!(seated.seats(), replayed.seats());
+        assert_eq!(seated.snapshot().await, replayed.snapshot().await);
+        assert_eq!(
+            seated.snapshot().await.center,
+            unseated.snapshot().await.center
+        );
+        tables.push(seated.seats().iter().map(|p| p.id()).collect::<Vec<_>>());
+    }
+
+    let joined: Vec<_> = (0..players.len()).map(PlayerId).collect();
+    assert!(tables.iter().any(|seats| seats != &joined));
+}
@@ -0,0 +1,439 @@
+use super::{init_logging, new_game, players, run_night, testplayer::Response};
+use crate::{
+    game::{
+        options::Options,
+        snapshot::{Observation, ObservedTarget},
+        PlayerId,
+    },
+    role::{
+        roles::{
+            apprenticeseer::ApprenticeSeer, auraseer::AuraSeer, beholder::Beholder, empath::Empath,
+            hunter::Hunter, insomniac::Insomniac, nostradamus::Nostradamus,
+            paranormalinvestigator::ParanormalInvestigator, robber::Robber, seer::Seer,
+            tanner::Tanner, thing::Thing, troublemaker::Troublemaker, villager::Villager,
+            werewolf::Werewolf, witch::Witch,
+        },
+        roletype::RoleType,
+        Role,
+    },
+};
+
+#[tokio::test(start_paused = true)]
+async fn apprentice_seer() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![Response::Num(1)])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(ApprenticeSeer::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Tanner::new()),
+            Box::new(Hunter::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let apprentice = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&apprentice).unwrap().observations,
+        [Observation::Role {
+            target: ObservedTarget::Center(1),
+            role: "Tanner".to_string()
+        }]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn paranormal_investigator_joins_werewolves() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Bool(true),
+            Response::Player(players[1].clone()),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(ParanormalInvestigator::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Villager::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let investigator = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&investigator).unwrap().observations.len(),
+        2
+    );
+    assert!(game.players_by_type()[&RoleType::Werewolf].contains(&investigator));
+    assert!(!game.players_by_type()[&RoleType::Villager].contains(&investigator));
+    assert_eq!(game.snapshot().await.players[0].team, "Werewolf");
+}
+
+#[tokio::test(start_paused = true)]
+async fn paranormal_investigator_stops_at_tanner() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![Response::Player(players[1].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(ParanormalInvestigator::new()),
+            Box::new(Tanner::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let investigator = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&investigator).unwrap().observations.len(),
+        1
+    );
+    assert_eq!(game.snapshot().await.players[0].team, "Tanner");
+}
+
+#[tokio::test(start_paused = true)]
+async fn witch() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Bool(true),
+            Response::Num(0),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Witch::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let witch = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&witch).unwrap().observations,
+        [Observation::Role {
+            target: ObservedTarget::Center(0),
+            role: "Werewolf".to_string()
+        }]
+    );
+
+    let target = game.seats()[1].clone();
+    assert_eq!(game.players()[&target].read().await.id(), "Werewolf");
+    assert_eq!(game.centerroles()[0].read().await.id(), "Villager");
+    assert!(game.players_by_type()[&RoleType::Werewolf].contains(&target));
+}
+
+#[tokio::test(start_paused = true)]
+async fn witch_swaps_own_card() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[0].clone()),
+            Response::Bool(true),
+            Response::Num(0),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Witch::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let witch = game.seats()[0].clone();
+    assert_eq!(game.players()[&witch].read().await.id(), "Werewolf");
+    assert_eq!(game.centerroles()[0].read().await.id(), "Witch");
+    assert!(game.players_by_type()[&RoleType::Werewolf].contains(&witch));
+    assert!(!game.players_by_type()[&RoleType::Villager].contains(&witch));
+}
+
+#[tokio::test(start_paused = true)]
+async fn aura_seer() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Robber::new()),
+            Box::new(Villager::new()),
+            Box::new(AuraSeer::new()),
+            Box::new(Insomniac::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    // the robbed player did nothing, and the insomniac only looks after the aura seer
+    let aura_seer = game.seats()[2].clone();
+    assert_eq!(
+        game.player_view(&aura_seer).unwrap().observations,
+        [Observation::Acted {
+            target: ObservedTarget::Player(PlayerId(0)),
+        }]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn beholder() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![Response::Bool(true)])
+        .await
+        .unwrap();
+    players[1]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Beholder::new()),
+            Box::new(Seer::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    // first the seer shows themselves, then the beholder looks at their card
+    let seer = Observation::Role {
+        target: ObservedTarget::Player(PlayerId(1)),
+        role: "Seer".to_string(),
+    };
+    let beholder = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&beholder).unwrap().observations,
+        [seer.clone(), seer]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn nostradamus() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![
+            Response::Bool(false),
+            Response::Player(players[2].clone()),
+            Response::Bool(true),
+            Response::Player(players[1].clone()),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Nostradamus::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Tanner::new()),
+            Box::new(Hunter::new()),
+            Box::new(Villager::new()),
+            Box::new(Witch::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let nostradamus = game.seats()[0].clone();
+    assert!(game.players_by_type()[&RoleType::Werewolf].contains(&nostradamus));
+    assert_eq!(game.snapshot().await.players[0].team, "Werewolf");
+}
+
+#[tokio::test(start_paused = true)]
+async fn nostradamus_after_troublemaker() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Bool(false),
+            Response::Player(players[2].clone()),
+        ])
+        .await
+        .unwrap();
+    players[1]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Player(players[0].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Nostradamus::new()),
+            Box::new(Troublemaker::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let nostradamus = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&nostradamus).unwrap().observations,
+        [Observation::Role {
+            target: ObservedTarget::Player(PlayerId(2)),
+            role: "Nostradamus".to_string()
+        }]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn thing_and_empath() {
+    init_logging();
+
+    let players = players(3);
+    // the thing taps player 2 and later points at player 1
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Player(players[2].clone()),
+        ])
+        .await
+        .unwrap();
+    players[2]
+        .push_choice(vec![Response::Player(players[0].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Thing::new()),
+            Box::new(Empath::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let tapped = game.seats()[2].clone();
+    assert_eq!(
+        game.player_view(&tapped).unwrap().observations,
+        [Observation::Role {
+            target: ObservedTarget::Player(PlayerId(0)),
+            role: "Thing".to_string(),
+        }]
+    );
+
+    let empath = game.seats()[1].clone();
+    assert_eq!(
+        game.player_view(&empath).unwrap().observations[1..],
+        [
+            Observation::Pointed {
+                pointer: ObservedTarget::Player(PlayerId(0)),
+                target: ObservedTarget::Player(PlayerId(1)),
+            },
+            Observation::Pointed {
+                pointer: ObservedTarget::Player(PlayerId(2)),
+                target: ObservedTarget::Player(PlayerId(0)),
+            },
+        ]
+    );
+}
@@ -1,5 +1,5 @@
 use crate::{
-    game::time::ONUWTime,
+    game::{artifact::Artifact, mark::Mark, snapshot::Reveal, time::ONUWTime, GamePlayer},
     playerinterface::{
         error::PlayerInterfaceError, message::Message, roletarget::RoleTarget, PlayerInterface,
     },
@@ -13,13 +13,13 @@ use futures::{
     },
     StreamExt,
 };
-use std::{collections::HashMap, fmt::Debug, sync::Arc};
+use std::{collections::BTreeMap, fmt::Debug, sync::Arc};
 use tokio::task::JoinHandle;
 use tracing::{debug, info, instrument, warn};
 
 enum Request {
     PushChoice(Vec<Response>),
-    ChoosePlayer(Vec<Arc<dyn PlayerInterface>>),
+    ChoosePlayer(Vec<GamePlayer>),
     ChooseBool(),
     ChooseNum(Vec<isize>),
     ShowMessage(Message),
@@ -30,16 +30,30 @@ pub enum Response {
     Player(Arc<dyn PlayerInterface>),
     Bool(bool),
     Num(isize),
+    /// Never answers the question, like a player who walked away from the game.
+    Stall,
 }
 
 #[derive(Debug)]
 pub struct TestPlayer {
     name: String,
     choices: Vec<Response>,
+    /// Whether to fall back to a default answer instead of failing once `choices` runs out.
+    default_answers: bool,
 }
 
 impl TestPlayer {
     pub fn init(name: String) -> TestPlayerInterface {
+        Self::spawn(name, false)
+    }
+
+    /// Like `init`, but once the pushed choices run out the player picks the first player or
+    /// number offered and answers yes to every question.
+    pub fn init_with_default_answers(name: String) -> TestPlayerInterface {
+        Self::spawn(name, true)
+    }
+
+    fn spawn(name: String, default_answers: bool) -> TestPlayerInterface {
         let (tx, mut rx) =
             unbounded::<(oneshot::Sender<Result<Option<Response>, String>>, Request)>();
 
@@ -49,9 +63,20 @@ impl TestPlayer {
             let mut self_ = Self {
                 name: name2,
                 choices: Vec::new(),
+                default_answers,
             };
 
+            let mut stalled = Vec::new();
+
             while let Some((tx1, req)) = rx.next().await {
+                if !matches!(req, Request::PushChoice(_) | Request::ShowMessage(_))
+                    && matches!(self_.choices.last(), Some(Response::Stall))
+                {
+                    self_.choices.pop();
+                    stalled.push(tx1);
+                    continue;
+                }
+
                 tx1.send(match req {
                     Request::PushChoice(c) => {
                         self_.push_choice(c);
@@ -88,11 +113,11 @@ impl TestPlayer {
     #[instrument(level = "trace")]
     fn choose_player(
         &mut self,
-        mut players: Vec<Arc<dyn PlayerInterface>>,
+        mut players: Vec<GamePlayer>,
     ) -> Result<Arc<dyn PlayerInterface>, String> {
         match self.choices.pop() {
             Some(Response::Player(player)) => {
-                if players.contains(&player) {
+                if players.iter().any(|p| Arc::ptr_eq(p.interface(), &player)) {
                     Ok(player)
                 } else {
                     players.sort();
@@ -107,6 +132,13 @@ impl TestPlayer {
                 "wrong choice type! was expecting Player, got {:?}",
                 r
             )),
+            None if self.default_answers => {
+                players.sort();
+                players
+                    .first()
+                    .map(|p| p.interface().clone())
+                    .ok_or("no players to choose from!".to_string())
+            }
             None => Err("ran out of choices!".to_string()),
         }
     }
@@ -122,6 +154,10 @@ impl TestPlayer {
                 }
             }
             Some(r) => Err(format!("wrong choice type! was expecting Num, got {:?}", r)),
+            None if self.default_answers => range
+                .first()
+                .copied()
+                .ok_or("no numbers to choose from!".to_string()),
             None => Err("ran out of choices!".to_string()),
         }
     }
@@ -134,6 +170,7 @@ impl TestPlayer {
                 "wrong choice type! was expecting Bool, got {:?}",
                 r
             )),
+            None if self.default_answers => Ok(true),
             None => Err("ran out of choices!".to_string()),
         }
     }
@@ -194,6 +231,82 @@ impl PlayerInterface for TestPlayerInterface {
         Ok(())
     }
 
+    #[instrument(level = "trace", skip(role))]
+    async fn show_face_up(
+        &self,
+        target: RoleTarget,
+        role: &dyn Role,
+    ) -> Result<(), PlayerInterfaceError> {
+        info!(
+            "Player {} was shown {:?}'s face up role of {:?}",
+            self.name(),
+            target,
+            role
+        );
+        Ok(())
+    }
+
+    #[instrument(level = "trace")]
+    async fn show_artifact(
+        &self,
+        target: RoleTarget,
+        artifact: &Artifact,
+    ) -> Result<(), PlayerInterfaceError> {
+        info!(
+            "Player {} was shown {:?}'s artifact {:?}",
+            self.name(),
+            target,
+            artifact
+        );
+        Ok(())
+    }
+
+    #[instrument(level = "trace")]
+    async fn show_mark(&self, target: RoleTarget, mark: &Mark) -> Result<(), PlayerInterfaceError> {
+        info!(
+            "Player {} was shown {:?}'s mark {:?}",
+            self.name(),
+            target,
+            mark
+        );
+        Ok(())
+    }
+
+    #[instrument(level = "trace")]
+    async fn show_instruction(&self, instruction: &str) -> Result<(), PlayerInterfaceError> {
+        info!(
+            "Player {} was given the instruction {:?}",
+            self.name(),
+            instruction
+        );
+        Ok(())
+    }
+
+    #[instrument(level = "trace")]
+    async fn show_acted(&self, target: RoleTarget) -> Result<(), PlayerInterfaceError> {
+        info!(
+            "Player {} was shown that {:?} moved or viewed a card",
+            self.name(),
+            target
+        );
+        Ok(())
+    }
+
+    #[instrument(level = "trace")]
+    async fn show_pointed(
+        &self,
+        pointer: RoleTarget,
+        target: RoleTarget,
+    ) -> Result<(), PlayerInterfaceError> {
+        info!(
+            "Player {} was shown that {:?} pointed at {:?}",
+            self.name(),
+            pointer,
+            target
+        );
+        Ok(())
+    }
+
     #[instrument(level = "trace")]
     async fn show_win(&self, won_game: bool) -> Result<(), PlayerInterfaceError> {
         info!(
@@ -205,6 +318,13 @@ impl PlayerInterface for TestPlayerInterface {
         Ok(())
     }
 
+    #[instrument(level = "trace")]
+    async fn show_reveal(&self, reveal: &Reveal) -> Result<(), PlayerInterfaceError> {
+        info!("Player {} was shown the final cards: {:#?}", self.name(), reveal);
+
+        Ok(())
+    }
+
     #[instrument(level = "trace")]
     async fn show_role_type(
         &self,
@@ -223,8 +343,8 @@ impl PlayerInterface for TestPlayerInterface {
     #[instrument(level = "trace")]
     async fn choose_player<'a>(
         &self,
-        players: &'a [&'a Arc<dyn PlayerInterface>],
-    ) -> Result<Arc<dyn PlayerInterface>, PlayerInterfaceError> {
+        players: &'a [&'a GamePlayer],
+    ) -> Result<GamePlayer, PlayerInterfaceError> {
         let (tx, rx) = oneshot::channel();
 
         self.send
@@ -234,9 +354,15 @@ impl PlayerInterface for TestPlayerInterface {
             ))
             .unwrap();
 
+        // the test picked an interface, hand back the player the game knows it as
         let resp = rx.await;
-        if let Ok(Ok(Some(Response::Player(player)))) = resp {
-            Ok(player)
+        if let Ok(Ok(Some(Response::Player(player)))) = &resp {
+            Ok(players
+                .iter()
+                .find(|p| Arc::ptr_eq(p.interface(), player))
+                .copied()
+                .unwrap()
+                .clone())
         } else {
             Err(PlayerInterfaceError::CommunicationError(format!(
                 "error choosing player: {:#?}",
@@ -245,6 +371,16 @@ impl PlayerInterface for TestPlayerInterface {
         }
     }
 
+    #[instrument(level = "trace")]
+    async fn point_at<'a>(
+        &self,
+        question: &str,
+        players: &'a [&'a GamePlayer],
+    ) -> Result<GamePlayer, PlayerInterfaceError> {
+        info!("Player {} was asked to point at {:?}", self.name(), question);
+        self.choose_player(players).await
+    }
+
     #[instrument(level = "trace")]
     async fn choose_bool(&self) -> Result<bool, PlayerInterfaceError> {
         let (tx, rx) = oneshot::channel();
@@ -286,13 +422,15 @@ impl PlayerInterface for TestPlayerInterface {
     #[instrument(level = "trace")]
     async fn handshake<'a>(
         &self,
-        players: &'a [&'a Arc<dyn PlayerInterface>],
-        roles: &'a HashMap<String, usize>,
+        players: &'a [&'a GamePlayer],
+        seats: &'a [&'a GamePlayer],
+        roles: &'a BTreeMap<String, usize>,
     ) -> Result<(), PlayerInterfaceError> {
         debug!(
-            "Player {} was greeted by {:?} with {:?}",
+            "Player {} was greeted by {:?} sitting as {:?} with {:?}",
             self.name(),
             players,
+            seats,
             roles
         );
         Ok(())
@@ -0,0 +1,570 @@
+use super::{init_logging, new_game, players, run_night, testplayer::Response};
+use crate::{
+    game::{
+        mark::Mark,
+        options::Options,
+        snapshot::{Observation, ObservedTarget},
+        PlayerId,
+    },
+    role::{
+        roles::{
+            apprenticeassassin::ApprenticeAssassin, assassin::Assassin, copycat::Copycat,
+            count::Count, cupid::Cupid, diseased::Diseased, gremlin::Gremlin, hunter::Hunter,
+            instigator::Instigator, marksman::Marksman, master::Master, minion::Minion,
+            pickpocket::Pickpocket, robber::Robber, seer::Seer, tanner::Tanner,
+            troublemaker::Troublemaker, vampire::Vampire, villager::Villager, werewolf::Werewolf,
+        },
+        roletype::RoleType,
+        Role,
+    },
+};
+
+#[tokio::test(start_paused = true)]
+async fn marks_stay_with_players() {
+    init_logging();
+
+    let players = players(4);
+    // the count marks player 1 as a vampire and puts fear on the seer
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[3].clone()),
+            Response::Player(players[1].clone()),
+        ])
+        .await
+        .unwrap();
+    players[2]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Count::new()),
+            Box::new(Villager::new()),
+            Box::new(Robber::new()),
+            Box::new(Seer::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    // the feared seer has no choices queued, so waking them would fail
+    run_night(&mut game).await;
+
+    let marked = game.seats()[1].clone();
+    let seer = game.seats()[3].clone();
+    assert_eq!(game.players()[&marked].read().await.id(), "Robber");
+    assert_eq!(game.mark_of(&marked), Mark::Vampire);
+    assert_eq!(game.mark_of(&seer), Mark::Fear);
+    assert!(game.players_by_type()[&RoleType::Vampire].contains(&marked));
+}
+
+#[tokio::test(start_paused = true)]
+async fn master() {
+    init_logging();
+
+    let players = players(4);
+    for i in [1, 2, 3] {
+        players[i]
+            .push_choice(vec![Response::Player(players[0].clone())])
+            .await
+            .unwrap();
+    }
+    players[0]
+        .push_choice(vec![Response::Player(players[2].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Master::new()),
+            Box::new(Vampire::new()),
+            Box::new(Villager::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Werewolf::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    // the vampire voted for the master, so the villagers' votes do not count
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(2)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn lovers_and_disease() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[3].clone()),
+            Response::Player(players[2].clone()),
+        ])
+        .await
+        .unwrap();
+    players[1]
+        .push_choice(vec![Response::Player(players[0].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Cupid::new()),
+            Box::new(Diseased::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let lover = game.seats()[2].clone();
+    assert_eq!(
+        game.player_view(&lover).unwrap().observations,
+        [Observation::Mark {
+            target: ObservedTarget::Player(PlayerId(2)),
+            mark: "Love".to_string(),
+        }]
+    );
+
+    for (voter, target) in [(0, 3), (1, 3), (2, 0), (3, 1)] {
+        players[voter]
+            .push_choice(vec![Response::Player(players[target].clone())])
+            .await
+            .unwrap();
+    }
+
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    // the villager dies with the werewolf they love and voted for the diseased cupid
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(2), PlayerId(3)]);
+    assert_eq!(reveal.winners, [PlayerId(0), PlayerId(1)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn traitor() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![Response::Player(players[1].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Instigator::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    for (voter, target) in [(0, 3), (1, 3), (2, 3), (3, 2)] {
+        players[voter]
+            .push_choice(vec![Response::Player(players[target].clone())])
+            .await
+            .unwrap();
+    }
+
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    // the traitor wins because another villager died
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(3)]);
+    assert_eq!(reveal.winners, [PlayerId(1), PlayerId(2)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn assassin() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![Response::Player(players[2].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Assassin::new()),
+            Box::new(ApprenticeAssassin::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    for (voter, target) in [(0, 3), (1, 2), (2, 3), (3, 2)] {
+        players[voter]
+            .push_choice(vec![Response::Player(players[target].clone())])
+            .await
+            .unwrap();
+    }
+
+    game.collect_votes().await.unwrap();
+    game.calc_dead_and_winners().await.unwrap();
+
+    // the villagers' win is taken from the assassinated villager
+    let reveal = game.reveal().await.unwrap();
+    assert_eq!(reveal.dead, [PlayerId(2), PlayerId(3)]);
+    assert_eq!(reveal.winners, [PlayerId(0)]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn marksman() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Player(players[3].clone()),
+        ])
+        .await
+        .unwrap();
+    players[1]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Player(players[1].clone()),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Marksman::new()),
+            Box::new(Cupid::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let marksman = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&marksman).unwrap().observations,
+        [
+            Observation::Role {
+                target: ObservedTarget::Player(PlayerId(3)),
+                role: "Werewolf".to_string(),
+            },
+            Observation::Mark {
+                target: ObservedTarget::Player(PlayerId(2)),
+                mark: "Love".to_string(),
+            },
+            Observation::Mark {
+                target: ObservedTarget::Player(PlayerId(0)),
+                mark: "Clarity".to_string(),
+            },
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn copycat() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Bool(true),
+            Response::Num(0),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Copycat::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Robber::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    // the copycat became the robber from the center and robbed the villager
+    let copycat = game.seats()[0].clone();
+    let villager = game.seats()[1].clone();
+    assert_eq!(game.players()[&copycat].read().await.id(), "Villager");
+    assert_eq!(
+        game.players()[&villager].read().await.verbose_id(),
+        "Copycat(Robber)"
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn pickpocket_and_gremlin() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![Response::Player(players[3].clone())])
+        .await
+        .unwrap();
+    players[1]
+        .push_choice(vec![
+            Response::Player(players[3].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+    players[2]
+        .push_choice(vec![
+            Response::Player(players[0].clone()),
+            Response::Player(players[1].clone()),
+            Response::Bool(true),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Instigator::new()),
+            Box::new(Pickpocket::new()),
+            Box::new(Gremlin::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    // the pickpocket stole the traitor mark, which the gremlin passed on to the instigator
+    let marks: Vec<_> = game.seats().iter().map(|p| game.mark_of(p)).collect();
+    assert_eq!(
+        marks,
+        [Mark::Traitor, Mark::Clarity, Mark::Clarity, Mark::Clarity]
+    );
+
+    let pickpocket = game.seats()[1].clone();
+    assert!(game
+        .player_view(&pickpocket)
+        .unwrap()
+        .observations
+        .contains(&Observation::Mark {
+            target: ObservedTarget::Player(PlayerId(1)),
+            mark: "Traitor".to_string(),
+        }));
+}
+
+/// The Robber's card is moved by the Gremlin before the Robber wakes, so the Robber swaps away
+/// the Werewolf card they now hold, not their own.
+#[tokio::test(start_paused = true)]
+async fn robber_after_gremlin() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+    players[3]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Player(players[0].clone()),
+            Response::Bool(false),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Robber::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Villager::new()),
+            Box::new(Gremlin::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let roles: Vec<_> = game
+        .seats()
+        .iter()
+        .map(|p| game.players()[p].try_read().unwrap().id())
+        .collect();
+    assert_eq!(roles, ["Villager", "Robber", "Werewolf", "Gremlin"]);
+    assert_eq!(
+        game.players_by_type()[&RoleType::Werewolf]
+            .iter()
+            .collect::<Vec<_>>(),
+        [&game.seats()[2]]
+    );
+}
+
+/// The Gremlin gives the Seer's card to the player the Seer then looks at.
+#[tokio::test(start_paused = true)]
+async fn seer_after_gremlin() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+    players[3]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Player(players[0].clone()),
+            Response::Bool(false),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Seer::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Gremlin::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let seer = game.seats()[0].clone();
+    assert!(game
+        .player_view(&seer)
+        .unwrap()
+        .observations
+        .contains(&Observation::Role {
+            target: ObservedTarget::Player(PlayerId(2)),
+            role: "Seer".to_string(),
+        }));
+}
+
+/// The Gremlin gives the Troublemaker's card to a player the Troublemaker then swaps.
+#[tokio::test(start_paused = true)]
+async fn troublemaker_after_gremlin() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![
+            Response::Player(players[1].clone()),
+            Response::Player(players[2].clone()),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+    players[3]
+        .push_choice(vec![
+            Response::Player(players[2].clone()),
+            Response::Player(players[0].clone()),
+            Response::Bool(false),
+            Response::Bool(true),
+        ])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Troublemaker::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Gremlin::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let roles: Vec<_> = game
+        .seats()
+        .iter()
+        .map(|p| game.players()[p].try_read().unwrap().id())
+        .collect();
+    assert_eq!(roles, ["Werewolf", "Troublemaker", "Villager", "Gremlin"]);
+    assert_eq!(
+        game.players_by_type()[&RoleType::Werewolf]
+            .iter()
+            .collect::<Vec<_>>(),
+        [&game.seats()[0]]
+    );
+}
@@ -0,0 +1,197 @@
+use super::{init_logging, new_game, players, run_night, testplayer::Response};
+use crate::{
+    game::{
+        options::Options,
+        snapshot::{Observation, ObservedTarget},
+        PlayerId,
+    },
+    role::{
+        roles::{
+            alphawolf::AlphaWolf, dreamwolf::DreamWolf, hunter::Hunter, minion::Minion,
+            mysticwolf::MysticWolf, squire::Squire, tanner::Tanner, villager::Villager,
+            werewolf::Werewolf,
+        },
+        roletype::RoleType,
+        Role,
+    },
+};
+
+#[tokio::test(start_paused = true)]
+async fn alpha_wolf() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![Response::Player(players[1].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(AlphaWolf::new()),
+            Box::new(Villager::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let victim = game.seats()[1].clone();
+    assert_eq!(game.players()[&victim].read().await.id(), "Werewolf");
+    assert!(game.players_by_type()[&RoleType::Werewolf].contains(&victim));
+
+    let snapshot = game.snapshot().await;
+    assert_eq!(snapshot.werewolf_card.unwrap().role, "Villager");
+    assert_eq!(
+        snapshot
+            .center
+            .iter()
+            .map(|c| c.role.as_str())
+            .collect::<Vec<_>>(),
+        ["Hunter", "Tanner", "Minion"]
+    );
+
+    let alpha = game.seats()[0].clone();
+    assert!(game
+        .player_view(&alpha)
+        .unwrap()
+        .observations
+        .contains(&Observation::RoleType {
+            target: ObservedTarget::Player(PlayerId(2)),
+            role_type: "Werewolf".to_string()
+        }));
+}
+
+#[tokio::test(start_paused = true)]
+async fn mystic_wolf() {
+    init_logging();
+
+    let players = players(3);
+    players[0]
+        .push_choice(vec![Response::Player(players[1].clone())])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(MysticWolf::new()),
+            Box::new(Tanner::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Villager::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let mystic = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&mystic).unwrap().observations,
+        [Observation::Role {
+            target: ObservedTarget::Player(PlayerId(1)),
+            role: "Tanner".to_string()
+        }]
+    );
+    assert!(game.snapshot().await.werewolf_card.is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn dream_wolf() {
+    init_logging();
+
+    let players = players(3);
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Werewolf::new()),
+            Box::new(DreamWolf::new()),
+            Box::new(Minion::new()),
+            Box::new(Hunter::new()),
+            Box::new(Villager::new()),
+            Box::new(Tanner::new()),
+        ],
+        Options::new().with_lone_wolf().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let seen_dream_wolf = Observation::RoleType {
+        target: ObservedTarget::Player(PlayerId(1)),
+        role_type: "Werewolf".to_string(),
+    };
+
+    // the werewolf sees the dream wolf, so it is not a lone wolf and views no center card
+    let werewolf = game.seats()[0].clone();
+    assert_eq!(
+        game.player_view(&werewolf).unwrap().observations,
+        std::slice::from_ref(&seen_dream_wolf)
+    );
+
+    let minion = game.seats()[2].clone();
+    assert!(game
+        .player_view(&minion)
+        .unwrap()
+        .observations
+        .contains(&seen_dream_wolf));
+
+    let dream_wolf = game.seats()[1].clone();
+    assert!(game
+        .player_view(&dream_wolf)
+        .unwrap()
+        .observations
+        .is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn squire() {
+    init_logging();
+
+    let players = players(4);
+    players[0]
+        .push_choice(vec![Response::Bool(true)])
+        .await
+        .unwrap();
+
+    let mut game = new_game(
+        &players,
+        vec![
+            Box::new(Squire::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Werewolf::new()),
+            Box::new(Villager::new()),
+            Box::new(Hunter::new()),
+            Box::new(Tanner::new()),
+            Box::new(Minion::new()),
+        ],
+        Options::new().debug_with_set_roles(),
+    )
+    .await;
+
+    run_night(&mut game).await;
+
+    let squire = game.seats()[0].clone();
+    let observations = game.player_view(&squire).unwrap().observations;
+    assert_eq!(observations.len(), 4);
+    for id in [1, 2] {
+        assert!(observations.contains(&Observation::RoleType {
+            target: ObservedTarget::Player(PlayerId(id)),
+            role_type: "Werewolf".to_string(),
+        }));
+        assert!(observations.contains(&Observation::Role {
+            target: ObservedTarget::Player(PlayerId(id)),
+            role: "Werewolf".to_string(),
+        }));
+    }
+}
@@ -1,6 +1,10 @@
+use crate::app::util::roleimg::role_image_name;
 use hashlink::LinkedHashMap;
 use leptos::*;
-use onuw_game::role::roles::{RoleDef, ROLES};
+use onuw_game::{
+    game::options::{Options, MAX_CENTER_COUNT},
+    role::roles::{RoleDef, ROLES},
+};
 use std::iter::repeat_with;
 
 #[derive(Clone, Copy)]
@@ -8,15 +12,26 @@ struct ActiveRoles(RwSignal<LinkedHashMap<StoredValue<RoleDef>, usize>>);
 
 #[component]
 pub fn GameConfig(
-    #[prop(default = Callback::new(|_|{();}), into)] on_click: Callback<
+    #[prop(default = Callback::new(|_|{();}), into)] on_click: Callback<(
         Vec<(StoredValue<RoleDef>, usize)>,
-    >,
+        usize,
+    )>,
     #[prop(default = "Start new game", into)] new_game_label: &'static str,
 ) -> impl IntoView {
-    let inactive_roles =
-        create_rw_signal(ROLES.keys().cloned().map(store_value).collect::<Vec<_>>()).read_only();
+    // the server may have loaded scripts and a role pack the client doesn't know about
+    let roles = create_resource(|| (), |_| get_roles());
+    let inactive_roles = create_memo(move |_| {
+        roles
+            .get()
+            .and_then(Result::ok)
+            .unwrap_or_default()
+            .into_iter()
+            .map(store_value)
+            .collect::<Vec<_>>()
+    });
     let active_roles = create_rw_signal(LinkedHashMap::new());
     let total_roles = create_rw_signal(0);
+    let center_count = create_rw_signal(*Options::default().center_count());
 
     provide_context(ActiveRoles(active_roles));
 
@@ -25,9 +40,17 @@ pub fn GameConfig(
             <div class="flex max-w-full">
                 <RolesView label="Available Roles:">
                     {move || {
-                        inactive_roles()
+                        group_by_expansion(inactive_roles())
                             .into_iter()
-                            .map(|v| view! { <RenderRole role=v/> })
+                            .map(|(expansion, roles)| {
+                                view! {
+                                    <h3 class="w-full text-center">{expansion}</h3>
+                                    {roles
+                                        .into_iter()
+                                        .map(|v| view! { <RenderRole role=v/> })
+                                        .collect_view()}
+                                }
+                            })
                             .collect_view()
                     }}
 
@@ -51,11 +74,28 @@ pub fn GameConfig(
                 </RolesView>
             </div>
             <div class="flex flex-col justify-center gap-1 max-w-fit mx-auto">
-                <p class="text-center">"Players: " {move || (total_roles() as isize) - 3}</p>
+                <label class="text-center">
+                    "Center cards: "
+                    <input
+                        type="number"
+                        min=0
+                        max=MAX_CENTER_COUNT
+                        prop:value=move || center_count().to_string()
+                        on:input=move |ev| {
+                            if let Ok(count) = event_target_value(&ev).parse::<usize>() {
+                                center_count.set(count.min(MAX_CENTER_COUNT));
+                            }
+                        }
+                    />
+
+                </label>
+                <p class="text-center">
+                    "Players: " {move || (total_roles() as isize) - (center_count() as isize)}
+                </p>
                 <button
                     class="bg-green-500 dark:bg-green-700"
-                    disabled=move || 4.gt(&total_roles())
-                    on:click=move |_| { start_game(active_roles, on_click) }
+                    disabled=move || (center_count() + 1).gt(&total_roles())
+                    on:click=move |_| { start_game(active_roles, center_count(), on_click) }
                 >
 
                     {new_game_label}
@@ -64,6 +104,11 @@ pub fn GameConfig(
         </div>
     }
 }
+#[server]
+async fn get_roles() -> Result<Vec<RoleDef>, ServerFnError> {
+    Ok(ROLES.keys().cloned().collect())
+}
+
 #[component]
 fn RolesView(label: &'static str, children: Children) -> impl IntoView {
     view! {
@@ -85,12 +130,38 @@ fn RenderRole(#[prop(default = false)] active: bool, role: StoredValue<RoleDef>)
         }>
             <img
                 class="w-32"
-                src=role.with_value(|v| format!("/assets/img/roles/{}.png", v.name.to_lowercase()))
+                src=role.with_value(|v| format!("/assets/img/roles/{}.png", role_image(v)))
+                alt=role.with_value(|v| v.display_name.clone())
+                title=role.with_value(|v| v.description.clone())
             />
         </div>
     }
 }
 
+/// The image of `role`, falling back to one named after the role if its pack has none.
+fn role_image(role: &RoleDef) -> String {
+    if role.image.is_empty() {
+        role_image_name(&role.name)
+    } else {
+        role.image.clone()
+    }
+}
+
+/// Groups `roles` by expansion, in the order each expansion first appears.
+fn group_by_expansion(
+    roles: Vec<StoredValue<RoleDef>>,
+) -> Vec<(String, Vec<StoredValue<RoleDef>>)> {
+    let mut expansions: Vec<(String, Vec<StoredValue<RoleDef>>)> = Vec::new();
+    for role in roles {
+        let expansion = role.with_value(|v| v.expansion.clone());
+        match expansions.iter_mut().find(|(name, _)| *name == expansion) {
+            Some((_, roles)) => roles.push(role),
+            None => expansions.push((expansion, vec![role])),
+        }
+    }
+    expansions
+}
+
 fn add_role(role: StoredValue<RoleDef>, v: &mut LinkedHashMap<StoredValue<RoleDef>, usize>) {
     if v.contains_key(&role) {
         let c = v.get_mut(&role).unwrap();
@@ -123,7 +194,8 @@ fn remove_role(role: StoredValue<RoleDef>, v: &mut LinkedHashMap<StoredValue<Rol
 
 fn start_game(
     active_roles: RwSignal<LinkedHashMap<StoredValue<RoleDef>, usize>>,
-    on_click: Callback<Vec<(StoredValue<RoleDef>, usize)>>,
+    center_count: usize,
+    on_click: Callback<(Vec<(StoredValue<RoleDef>, usize)>, usize)>,
 ) {
     let selected_roles: Vec<_> = active_roles
         .get()
@@ -131,6 +203,6 @@ fn start_game(
         .map(|(role, amt)| (*role, *amt))
         .collect();
 
-    on_click(selected_roles);
+    on_click((selected_roles, center_count));
 }
 
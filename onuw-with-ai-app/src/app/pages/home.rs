@@ -1,9 +1,16 @@
-use crate::app::components::startgame::GameConfig;
+use crate::{
+    app::components::startgame::GameConfig,
+    gamemanager::{GameId, GameStatus},
+};
 use fallible_iterator::{FallibleIterator, IteratorExt};
 use leptos::*;
 use leptos_router::*;
+use leptos_use::{use_interval, UseIntervalReturn};
 use onuw_game::{
-    game::{options::Options, ONUWGame},
+    game::{
+        options::{NightTurnLength, Options, MAX_CENTER_COUNT},
+        snapshot::Reveal,
+    },
     playerinterface::PlayerInterface,
     role::{
         roles::{RoleDef, ROLES, ROLES_STRINGS},
@@ -14,6 +21,8 @@ use std::time::Duration;
 use std::{iter::repeat_with, sync::Arc};
 use tracing::info;
 
+#[cfg(feature = "ssr")]
+use crate::gamemanager::GAME_MANAGER;
 #[cfg(feature = "ssr")]
 use onuw_agent::interface::{error::AgentError, AgentInterface};
 #[cfg(feature = "ssr")]
@@ -29,13 +38,30 @@ enum GameType {
 pub fn HomePage() -> impl IntoView {
     let btn_active = create_rw_signal(None);
 
-    let start_game_action = create_action(|x: &Vec<(StoredValue<RoleDef>, usize)>| {
-        run_game(
-            x.iter()
-                .map(|(v, amt)| (v.get_value().name, *amt))
-                .collect(),
-        )
-    });
+    let start_game_action = create_action(
+        |(x, center_count): &(Vec<(StoredValue<RoleDef>, usize)>, usize)| {
+            create_game(
+                x.iter()
+                    .map(|(v, amt)| (v.get_value().name, *amt))
+                    .collect(),
+                *center_count,
+            )
+        },
+    );
+
+    let game_id = move || start_game_action.value().get().and_then(Result::ok);
+
+    let UseIntervalReturn { counter, .. } = use_interval(1000);
+
+    let game_status = create_resource(
+        move || (game_id(), counter()),
+        |(id, _)| async move {
+            match id {
+                Some(id) => get_game_status(id).await.ok(),
+                None => None,
+            }
+        },
+    );
 
     create_effect(move |_| info!("active: {:?}", btn_active()));
 
@@ -79,21 +105,18 @@ pub fn HomePage() -> impl IntoView {
             }}
 
             {move || {
-                start_game_action
-                    .value()
+                game_status
                     .get()
-                    .map(|v| {
-                        if let Ok((votes, dead, winners, roles)) = v {
-                            Some(
-                                view! {
-                                    <p>{move || format!("Votes: {:#?}", votes)}</p>
-                                    <p>{move || format!("Dead: {:#?}", dead)}</p>
-                                    <p>{move || format!("Winners: {:#?}", winners)}</p>
-                                    <p>{move || format!("Roles: {:#?}", roles)}</p>
-                                },
-                            )
-                        } else {
-                            None
+                    .flatten()
+                    .map(|status| match status {
+                        GameStatus::Starting => view! { <p>"Starting game..."</p> }.into_view(),
+                        GameStatus::Night => view! { <p>"It is night..."</p> }.into_view(),
+                        GameStatus::Voting => view! { <p>"Voting..."</p> }.into_view(),
+                        GameStatus::Finished(reveal) => {
+                            view! { <GameResults reveal/> }.into_view()
+                        }
+                        GameStatus::Failed(error) => {
+                            view! { <p>{format!("Game failed: {}", error)}</p> }.into_view()
                         }
                     })
             }}
@@ -102,10 +125,89 @@ pub fn HomePage() -> impl IntoView {
     }
 }
 
+#[component]
+fn GameResults(reveal: Reveal) -> impl IntoView {
+    let players = reveal
+        .players
+        .into_iter()
+        .map(|p| {
+            let outcome = match (reveal.dead.contains(&p.id), reveal.winners.contains(&p.id)) {
+                (true, true) => "died, won",
+                (true, false) => "died, lost",
+                (false, true) => "won",
+                (false, false) => "lost",
+            };
+
+            view! {
+                <tr>
+                    <td>{p.name}</td>
+                    <td>{p.dealt_role}</td>
+                    <td>{p.role}</td>
+                    <td>{p.team}</td>
+                    <td>{outcome}</td>
+                </tr>
+            }
+        })
+        .collect_view();
+
+    let center = reveal
+        .center
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| (format!("Center {}", i + 1), c))
+        .chain(
+            reveal
+                .werewolf_card
+                .map(|c| ("Werewolf card".to_string(), c)),
+        )
+        .map(|(label, c)| {
+            view! {
+                <tr>
+                    <td>{label}</td>
+                    <td>{c.dealt_role}</td>
+                    <td>{c.role}</td>
+                    <td></td>
+                    <td></td>
+                </tr>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <h2>
+            {if reveal.winning_teams.is_empty() {
+                "Nobody won.".to_string()
+            } else {
+                format!("Winning teams: {}", reveal.winning_teams.join(", "))
+            }}
+
+        </h2>
+        <table>
+            <tr>
+                <th>"Player"</th>
+                <th>"Dealt"</th>
+                <th>"Final"</th>
+                <th>"Team"</th>
+                <th>"Outcome"</th>
+            </tr>
+            {players}
+            {center}
+        </table>
+    }
+}
+
 #[server]
-async fn run_game(
+async fn create_game(
     requested_roles: Vec<(String, usize)>,
-) -> Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>), ServerFnError> {
+    center_count: usize,
+) -> Result<GameId, ServerFnError> {
+    if center_count > MAX_CENTER_COUNT {
+        return Err(ServerFnError::ServerError(format!(
+            "at most {} cards can be in the center",
+            MAX_CENTER_COUNT
+        )));
+    }
+
     let roles: Vec<_> = requested_roles
         .into_iter()
         .map(|(rname, amt)| {
@@ -120,9 +222,11 @@ async fn run_game(
         .transpose_into_fallible()
         .map(|(rdef, amt)| {
             if (rdef.min_amt..=rdef.max_amt).contains(&amt) {
-                Ok(repeat_with(|| Ok(ROLES.get(rdef).unwrap().unwrap()()))
-                    .take(amt)
-                    .transpose_into_fallible())
+                Ok(
+                    repeat_with(|| Ok(ROLES.get(rdef).unwrap().as_ref().unwrap()()))
+                        .take(amt)
+                        .transpose_into_fallible(),
+                )
             } else {
                 Err(ServerFnError::ServerError(format!(
                     "wrong amount of {}",
@@ -133,8 +237,23 @@ async fn run_game(
         .flatten()
         .collect()?;
 
+    if roles.len() <= center_count {
+        return Err(ServerFnError::ServerError(format!(
+            "{} roles leave nobody to play with {} center cards",
+            roles.len(),
+            center_count
+        )));
+    }
+
     info!("Starting new game with roles: {:#?}", roles);
 
+    // center roles get turns as long as everyone else's, so their absence doesn't show
+    let options = Options::new()
+        .with_center_count(center_count)
+        .with_night_turn_length(NightTurnLength::Random {
+            min: Duration::from_secs(5),
+            max: Duration::from_secs(15),
+        });
     let mut count = 0;
 
     let players: Vec<_> = repeat_with(|| {
@@ -144,49 +263,20 @@ async fn run_game(
                 as Arc<dyn PlayerInterface>,
         )
     })
-    .take(roles.len() - 3)
+    .take(roles.len().saturating_sub(*options.center_count()))
     .transpose_into_fallible()
     .collect()?;
 
-    let mut game = ONUWGame::new(players, roles, Options::default()).await?;
-
-    game.send_handshake().await;
-
-    game.show_all_roles().await;
-
-    while game.peek_next_night_action().is_some() {
-        game.perform_next_night_action(|| async {}).await?;
-    }
+    Ok(GAME_MANAGER.create_game(players, roles, options).await)
+}
 
-    game.collect_votes().await?;
-
-    game.calc_dead_and_winners().await?;
-
-    Ok((
-        game.votes()
-            .as_ref()
-            .unwrap()
-            .iter()
-            .map(|(p, v)| format!("{}: {}", p.name(), v.name()))
-            .collect(),
-        game.dead()
-            .as_ref()
-            .unwrap()
-            .clone()
-            .into_iter()
-            .map(|v| v.name().to_string())
-            .collect(),
-        game.winners()
-            .as_ref()
-            .unwrap()
-            .clone()
-            .into_iter()
-            .map(|v| v.name().to_string())
-            .collect(),
-        game.players()
-            .iter()
-            .map(|(p, v)| format!("{}: {}", p.name(), v.try_read().unwrap().verbose_id()))
-            .collect(),
-    ))
-    // Ok(([].to_vec(), [].to_vec()))
+#[server]
+async fn get_game_status(id: GameId) -> Result<GameStatus, ServerFnError> {
+    GAME_MANAGER
+        .status(id)
+        .await
+        .ok_or(ServerFnError::ServerError(format!(
+            "no game with id {}",
+            id
+        )))
 }
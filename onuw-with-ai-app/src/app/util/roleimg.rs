@@ -16,3 +16,17 @@ pub static ROLE_IMAGE_VARIANTS: Lazy<HashMap<&str, usize>> = Lazy::new(|| {
     .collect()
 });
 
+
+/// Turns a role name like `AlphaWolf` into the stem of its image file, `alpha_wolf`.
+pub fn role_image_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 2);
+
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i != 0 {
+            out.push('_');
+        }
+        out.extend(c.to_lowercase());
+    }
+
+    out
+}
@@ -0,0 +1,433 @@
+use cfg_if::cfg_if;
+use onuw_game::game::snapshot::Reveal;
+use serde::{Deserialize, Serialize};
+
+pub type GameId = u64;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum GameStatus {
+    Starting,
+    Night,
+    Voting,
+    Finished(Reveal),
+    Failed(String),
+}
+
+cfg_if! { if #[cfg(feature = "ssr")] {
+
+use onuw_game::{
+    game::{options::Options, ONUWGame},
+    playerinterface::PlayerInterface,
+    role::Role,
+};
+use once_cell::sync::Lazy;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::{
+    sync::RwLock,
+    task::{AbortHandle, JoinHandle},
+    time::Instant,
+};
+use tracing::{info, warn};
+
+/// Finished games are kept around this long after their results were last requested.
+const FINISHED_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Running games nobody has asked about for this long are considered abandoned.
+const ABANDONED_TTL: Duration = Duration::from_secs(30 * 60);
+
+pub static GAME_MANAGER: Lazy<GameManager> = Lazy::new(GameManager::default);
+
+struct ManagedGame {
+    status: Arc<RwLock<GameStatus>>,
+    /// The supervisor of the game task, which finishes once the game's outcome is recorded.
+    task: JoinHandle<()>,
+    game: AbortHandle,
+    last_accessed: Instant,
+}
+
+#[derive(Default)]
+pub struct GameManager {
+    games: RwLock<HashMap<GameId, ManagedGame>>,
+    next_id: AtomicU64,
+}
+
+impl GameManager {
+    /// Starts a new game on a background task and returns its id.
+    pub async fn create_game(
+        &self,
+        players: Vec<Arc<dyn PlayerInterface>>,
+        roles: Vec<Box<dyn Role>>,
+        options: Options,
+    ) -> GameId {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let status = Arc::new(RwLock::new(GameStatus::Starting));
+
+        let game = tokio::spawn(Self::drive(id, players, roles, options, status.clone()));
+        let abort = game.abort_handle();
+        let task = tokio::spawn(Self::supervise(id, game, status.clone()));
+
+        self.games.write().await.insert(
+            id,
+            ManagedGame {
+                status,
+                task,
+                game: abort,
+                last_accessed: Instant::now(),
+            },
+        );
+
+        info!("created game {}", id);
+        id
+    }
+
+    /// Returns the current status of a game, or `None` if it doesn't exist (anymore).
+    pub async fn status(&self, id: GameId) -> Option<GameStatus> {
+        let mut games = self.games.write().await;
+        let game = games.get_mut(&id)?;
+        game.last_accessed = Instant::now();
+
+        let status = game.status.read().await.clone();
+        Some(status)
+    }
+
+    /// Removes finished games whose results are no longer being looked at and stops abandoned ones.
+    pub async fn cleanup(&self) {
+        let mut games = self.games.write().await;
+        let now = Instant::now();
+
+        let mut expired = Vec::new();
+        for (id, game) in games.iter() {
+            let idle = now.duration_since(game.last_accessed);
+
+            if game.task.is_finished() {
+                if idle > FINISHED_TTL {
+                    expired.push(*id);
+                }
+            } else if idle > ABANDONED_TTL {
+                warn!("stopping abandoned game {}", id);
+                game.game.abort();
+                expired.push(*id);
+            }
+        }
+
+        for id in expired {
+            games.remove(&id);
+            info!("removed game {}", id);
+        }
+    }
+
+    /// Periodically runs [`GameManager::cleanup`] for as long as the server is up.
+    pub fn spawn_cleanup(&'static self, interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(interval);
+            loop {
+                interval.tick().await;
+                self.cleanup().await;
+            }
+        })
+    }
+
+    /// Waits for a game's task and marks the game as failed if it panicked or was stopped, so
+    /// its status doesn't stay stuck at night or voting.
+    async fn supervise(id: GameId, game: JoinHandle<()>, status: Arc<RwLock<GameStatus>>) {
+        let Err(e) = game.await else {
+            return;
+        };
+
+        let reason = if e.is_panic() {
+            let panic = e.into_panic();
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown error".to_string());
+            warn!("game {} panicked: {}", id, message);
+            format!("the game crashed: {}", message)
+        } else {
+            warn!("game {} was stopped", id);
+            "the game was stopped".to_string()
+        };
+
+        *status.write().await = GameStatus::Failed(reason);
+    }
+
+    async fn drive(
+        id: GameId,
+        players: Vec<Arc<dyn PlayerInterface>>,
+        roles: Vec<Box<dyn Role>>,
+        options: Options,
+        status: Arc<RwLock<GameStatus>>,
+    ) {
+        let mut game = match ONUWGame::new(players, roles, options).await {
+            Ok(game) => game,
+            Err(e) => {
+                *status.write().await = GameStatus::Failed(e.to_string());
+                return;
+            }
+        };
+
+        game.send_handshake().await;
+        game.show_all_roles().await;
+
+        *status.write().await = GameStatus::Night;
+
+        while game.peek_next_night_action().is_some() {
+            if let Err(e) = game.perform_next_night_action().await {
+                *status.write().await = GameStatus::Failed(e.to_string());
+                return;
+            }
+        }
+
+        if let Err(e) = game.start_day().await {
+            *status.write().await = GameStatus::Failed(e.to_string());
+            return;
+        }
+
+        *status.write().await = GameStatus::Voting;
+
+        let result = match game.collect_votes().await {
+            Ok(()) => game.calc_dead_and_winners().await,
+            Err(e) => Err(e),
+        };
+
+        *status.write().await = match result {
+            Ok(()) => match game.reveal().await {
+                Some(reveal) => GameStatus::Finished(reveal),
+                None => GameStatus::Failed("game ended without a result".to_string()),
+            },
+            Err(e) => GameStatus::Failed(e.to_string()),
+        };
+
+        info!("game {} finished", id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use onuw_game::{
+        game::{artifact::Artifact, mark::Mark, snapshot::Reveal, time::ONUWTime, GamePlayer},
+        playerinterface::{error::PlayerInterfaceError, message::Message, roletarget::RoleTarget},
+        role::{roles::villager::Villager, roletype::RoleType},
+    };
+    use std::collections::BTreeMap;
+
+    /// How a [`TestPlayer`] answers when asked to choose a player.
+    #[derive(Clone, Copy)]
+    enum Answer {
+        First,
+        Panic,
+        Never,
+    }
+
+    struct TestPlayer {
+        name: String,
+        answer: Answer,
+    }
+
+    #[async_trait]
+    impl PlayerInterface for TestPlayer {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        async fn show_role(&self, _: RoleTarget, _: &dyn Role) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn show_role_type(
+            &self,
+            _: RoleTarget,
+            _: &RoleType,
+        ) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn show_face_up(
+            &self,
+            _: RoleTarget,
+            _: &dyn Role,
+        ) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn show_artifact(
+            &self,
+            _: RoleTarget,
+            _: &Artifact,
+        ) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn show_mark(&self, _: RoleTarget, _: &Mark) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn show_instruction(&self, _: &str) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn show_acted(&self, _: RoleTarget) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn show_pointed(
+            &self,
+            _: RoleTarget,
+            _: RoleTarget,
+        ) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn choose_player<'a>(
+            &self,
+            players: &'a [&'a GamePlayer],
+        ) -> Result<GamePlayer, PlayerInterfaceError> {
+            match self.answer {
+                Answer::First => Ok(players[0].clone()),
+                Answer::Panic => panic!("{} cannot choose", self.name),
+                Answer::Never => std::future::pending().await,
+            }
+        }
+
+        async fn choose_bool(&self) -> Result<bool, PlayerInterfaceError> {
+            Ok(false)
+        }
+
+        async fn point_at<'a>(
+            &self,
+            _: &str,
+            players: &'a [&'a GamePlayer],
+        ) -> Result<GamePlayer, PlayerInterfaceError> {
+            Ok(players[0].clone())
+        }
+
+        async fn choose_num(&self, choices: &[isize]) -> Result<isize, PlayerInterfaceError> {
+            Ok(choices[0])
+        }
+
+        async fn receive_message(&self, _: &Message) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn handshake<'a>(
+            &self,
+            _: &'a [&'a GamePlayer],
+            _: &'a [&'a GamePlayer],
+            _: &'a BTreeMap<String, usize>,
+        ) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn show_time(&self, _: &ONUWTime) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn show_win(&self, _: bool) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+
+        async fn show_reveal(&self, _: &Reveal) -> Result<(), PlayerInterfaceError> {
+            Ok(())
+        }
+    }
+
+    async fn start(manager: &GameManager, answer: Answer) -> GameId {
+        let players = (0..3)
+            .map(|i| {
+                Arc::new(TestPlayer {
+                    name: i.to_string(),
+                    answer,
+                }) as Arc<dyn PlayerInterface>
+            })
+            .collect();
+        let roles = (0..6).map(|_| Box::new(Villager::new()) as _).collect();
+
+        manager
+            .create_game(players, roles, Options::default())
+            .await
+    }
+
+    /// Polls the game until it is over.
+    async fn outcome(manager: &GameManager, id: GameId) -> GameStatus {
+        loop {
+            match manager.status(id).await.unwrap() {
+                status @ (GameStatus::Finished(_) | GameStatus::Failed(_)) => return status,
+                _ => tokio::time::sleep(Duration::from_millis(100)).await,
+            }
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn create_game_and_status() {
+        let manager = GameManager::default();
+
+        let id = start(&manager, Answer::First).await;
+        assert_ne!(start(&manager, Answer::First).await, id);
+        assert!(manager.status(id + 2).await.is_none());
+
+        assert!(matches!(
+            outcome(&manager, id).await,
+            GameStatus::Finished(_)
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn panicking_game_fails() {
+        let manager = GameManager::default();
+        let id = start(&manager, Answer::Panic).await;
+
+        match outcome(&manager, id).await {
+            GameStatus::Failed(reason) => assert!(reason.contains("cannot choose")),
+            status => panic!("expected the game to fail, got {:?}", status),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cleanup_removes_finished_games() {
+        let manager = GameManager::default();
+        let id = start(&manager, Answer::First).await;
+        outcome(&manager, id).await;
+        while !manager.games.read().await[&id].task.is_finished() {
+            tokio::task::yield_now().await;
+        }
+
+        tokio::time::advance(FINISHED_TTL - Duration::from_secs(1)).await;
+        manager.cleanup().await;
+        assert!(manager.status(id).await.is_some());
+
+        tokio::time::advance(FINISHED_TTL + Duration::from_secs(1)).await;
+        manager.cleanup().await;
+        assert!(manager.status(id).await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cleanup_stops_abandoned_games() {
+        let manager = GameManager::default();
+        let id = start(&manager, Answer::Never).await;
+        let status = manager.games.read().await[&id].status.clone();
+
+        tokio::time::advance(ABANDONED_TTL - Duration::from_secs(1)).await;
+        manager.cleanup().await;
+        assert!(manager.status(id).await.is_some());
+
+        tokio::time::advance(ABANDONED_TTL + Duration::from_secs(1)).await;
+        manager.cleanup().await;
+        assert!(manager.status(id).await.is_none());
+
+        // the supervisor still records why the game ended
+        while !matches!(*status.read().await, GameStatus::Failed(_)) {
+            tokio::task::yield_now().await;
+        }
+    }
+}
+
+}}
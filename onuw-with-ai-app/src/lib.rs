@@ -1,5 +1,6 @@
 pub mod app;
 pub mod fileserv;
+pub mod gamemanager;
 
 use cfg_if::cfg_if;
 
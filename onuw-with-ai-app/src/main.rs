@@ -1,5 +1,6 @@
 mod app;
 mod fileserv;
+mod gamemanager;
 
 use std::error::Error;
 
@@ -9,8 +10,11 @@ async fn main() -> Result<(), Box<dyn Error>> {
     use app::*;
     use axum::{routing::post, Router};
     use fileserv::file_and_error_handler;
+    use gamemanager::GAME_MANAGER;
     use leptos::*;
     use leptos_axum::{generate_route_list, LeptosRoutes};
+    use onuw_game::role::roles::ROLES;
+    use std::time::Duration;
     use tracing_subscriber::{
         filter::{EnvFilter, LevelFilter},
         fmt,
@@ -34,6 +38,12 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let addr = leptos_options.site_addr;
     let routes = generate_route_list(App);
 
+    GAME_MANAGER.spawn_cleanup(Duration::from_secs(60));
+
+    // load the scripts in ONUW_SCRIPTS and the pack in ONUW_ROLE_PACK now, so problems with
+    // them are logged before anyone starts a game
+    tracing::info!("{} roles available", ROLES.len());
+
     // build our application with a route
     let app = Router::new()
         .route("/api/*fn_name", post(leptos_axum::handle_server_fns))